@@ -2,6 +2,7 @@ use dioxus::prelude::*;
 use dioxus_logger::tracing;
 use scroll_rs::dioxus::Scroll;
 use scroll_rs::Behavior;
+use scroll_rs::ScrollTarget;
 
 fn main() {
     dioxus_logger::init(tracing::Level::INFO).expect("failed to init logger");
@@ -72,7 +73,7 @@ pub fn Home() -> Element {
                                 }
                             }
                         },
-                        scroll_id: "bottom-scroll",
+                        target: ScrollTarget::id("bottom-scroll"),
                     }
                 }
                 // Scroll Left
@@ -95,7 +96,7 @@ pub fn Home() -> Element {
                                 }
                             }
                         },
-                        left: -500.0,
+                        target: ScrollTarget::By { dx: -500.0, dy: 0.0 },
                         show_id: "left-scroll",
                     }
                 }
@@ -119,7 +120,7 @@ pub fn Home() -> Element {
                                 }
                             }
                         },
-                        left: 500.0,
+                        target: ScrollTarget::By { dx: 500.0, dy: 0.0 },
                     }
                 }
                 // Delayed Scroll to Top
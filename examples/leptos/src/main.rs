@@ -1,6 +1,7 @@
 use leptos::prelude::*;
 use scroll_rs::leptos::Scroll;
 use scroll_rs::Behavior;
+use scroll_rs::ScrollTarget;
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -35,8 +36,8 @@ pub fn Home() -> impl IntoView {
             <div title="Scroll to Bottom">
                 <Scroll
                     style="position: fixed; bottom: 4rem; right: 3rem; background-color: #10B981; color: #FFFFFF; padding: 1rem; border-radius: 50%; cursor: pointer; box-shadow: 0px 4px 12px rgba(0, 0, 0, 0.4);"
-                    icon="↓"
-                    scroll_id="bottom-scroll"
+                    icon=|| "↓"
+                    target=ScrollTarget::id("bottom-scroll")
                 />
             </div>
 
@@ -44,8 +45,8 @@ pub fn Home() -> impl IntoView {
             <div title="Scroll to the Left">
                 <Scroll
                     style="position: fixed; top: 40%; left: 2rem; background-color: #E11D48; color: #FFFFFF; padding: 1rem; border-radius: 50%; cursor: pointer; box-shadow: 0px 4px 12px rgba(0, 0, 0, 0.4);"
-                    icon="←"
-                    left={-500.0}
+                    icon=|| "←"
+                    target=ScrollTarget::By { dx: -500.0, dy: 0.0 }
                     show_id="left-scroll"
                 />
             </div>
@@ -54,8 +55,8 @@ pub fn Home() -> impl IntoView {
             <div title="Scroll to the Right">
                 <Scroll
                     style="position: fixed; top: 40%; right: 2rem; background-color: #F59E0B; color: #FFFFFF; padding: 1rem; border-radius: 50%; cursor: pointer; box-shadow: 0px 4px 12px rgba(0, 0, 0, 0.4);"
-                    icon="→"
-                    left=500.0
+                    icon=|| "→"
+                    target=ScrollTarget::By { dx: 500.0, dy: 0.0 }
                 />
             </div>
 
@@ -1,5 +1,6 @@
 use scroll_rs::yew::Scroll;
 use scroll_rs::Behavior;
+use scroll_rs::ScrollTarget;
 use yew::prelude::*;
 
 #[function_component(Home)]
@@ -31,7 +32,7 @@ pub fn home() -> Html {
                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M19 9l-7 7-7-7" />
                             </svg>
                         }}
-                        scroll_id="bottom-scroll"
+                        target={ScrollTarget::id("bottom-scroll")}
                     />
                 </div>
                 // Scroll Left
@@ -43,7 +44,7 @@ pub fn home() -> Html {
                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 19l-7-7 7-7" />
                             </svg>
                         }}
-                        left={-500.0}
+                        target={ScrollTarget::By { dx: -500.0, dy: 0.0 }}
                         show_id="left-scroll"
                     />
                 </div>
@@ -56,7 +57,7 @@ pub fn home() -> Html {
                                 <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M9 5l7 7-7 7" />
                             </svg>
                         }}
-                        left=500.0
+                        target={ScrollTarget::By { dx: 500.0, dy: 0.0 }}
                     />
                 </div>
                 // Delayed Scroll to Top
@@ -1,10 +1,102 @@
-#[derive(Clone, PartialEq)]
+mod aria;
+mod container;
+mod controller;
+mod easing;
+mod engine;
+mod error;
+mod frame;
+mod hash;
+mod hub;
+mod lock;
+mod motion;
+mod registry;
+mod restore;
+mod sentinel;
+mod spy;
+mod state;
+mod target;
+mod task;
+#[cfg(all(test, target_arch = "wasm32"))]
+pub(crate) mod testing;
+
+pub use aria::ScrollAria;
+pub use container::{ScrollContainer, ScrollListener, ScrollRoot};
+pub use controller::{ScrollController, ScrollOptions};
+pub use easing::Easing;
+pub use engine::{ScrollAlign, ScrollEngine, ScrollRequest};
+pub use error::ScrollError;
+pub use hash::{HashMode, HashScroll, HashScrollOptions};
+pub use lock::{ScrollLock, ScrollLockOptions};
+pub use motion::ReducedMotion;
+pub use restore::{ScrollRestoration, ScrollRestorationOptions};
+pub use sentinel::{SentinelObserver, SENTINEL_ROOT_MARGIN};
+pub use spy::{ScrollSpyObserver, ScrollSpyOptions};
+pub use state::{
+    ScrollDirection, ScrollState, ScrollStateObserver, ScrollStateOptions, ScrollTracker,
+};
+pub use target::{NodeHandle, ScrollTarget};
+pub use task::{ScrollOutcome, ScrollTask};
+
+use web_sys::{ScrollBehavior, Window};
+
+/// The browser window, or `None` outside the browser.
+///
+/// `web_sys` panics when it is called on a native target, so nothing may reach
+/// it while rendering on the server. Every DOM access in this crate starts from
+/// this function, which turns it into a no-op during server-side rendering.
+pub(crate) fn window() -> Option<Window> {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window()
+    } else {
+        None
+    }
+}
+
+/// How a scroll is carried out.
+///
+/// `Smooth` and `Animated` scrolls become instant while the user prefers reduced
+/// motion, unless [`ReducedMotion`] says otherwise.
+#[derive(Clone, PartialEq, Debug, Default)]
 pub enum Behavior {
+    /// Let the browser decide, based on the CSS `scroll-behavior` property.
     Auto,
+    /// Jump straight to the target.
     Instant,
+    /// Use the browser's native smooth scrolling.
+    #[default]
     Smooth,
+    /// Animate the scroll with a `requestAnimationFrame` loop.
+    ///
+    /// Unlike `Smooth`, the duration and timing curve are the same in every
+    /// browser.
+    Animated {
+        /// Total animation time in milliseconds.
+        duration_ms: u32,
+        /// Timing curve applied to the animation progress.
+        easing: Easing,
+    },
+}
+
+impl From<&Behavior> for ScrollBehavior {
+    fn from(behavior: &Behavior) -> Self {
+        match behavior {
+            Behavior::Auto => ScrollBehavior::Auto,
+            Behavior::Instant => ScrollBehavior::Instant,
+            Behavior::Smooth => ScrollBehavior::Smooth,
+            // Each animation frame jumps to an intermediate position.
+            Behavior::Animated { .. } => ScrollBehavior::Instant,
+        }
+    }
 }
 
 /// Default CSS style for the scroll-to-top button.
+///
+/// Resets the native `<button>` border so the button looks the same in every browser.
 pub const SCROLL_TO_TOP_STYLE: &str =
-    "position: fixed; bottom: 1rem; right: 1rem; background-color: #3b82f6; color: #ffffff; padding: 0.75rem; border-radius: 50%; cursor: pointer; transition: background-color 300ms ease-in-out;";
+    "position: fixed; bottom: 1rem; right: 1rem; background-color: #3b82f6; color: #ffffff; padding: 0.75rem; border: none; border-radius: 50%; cursor: pointer; transition: background-color 300ms ease-in-out;";
+
+/// Default CSS style for the reading-progress bar.
+///
+/// The bar's width is appended at render time from the current progress.
+pub const SCROLL_PROGRESS_STYLE: &str =
+    "position: fixed; top: 0; left: 0; height: 4px; background-color: #3b82f6; z-index: 50; transition: width 100ms linear;";
@@ -0,0 +1,54 @@
+use crate::common::ScrollTarget;
+
+/// ARIA attributes for a custom scroll trigger.
+///
+/// Headless components hand these to your render function, so a button from
+/// your own design system is announced the same way as the built-in one.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::{ScrollAria, ScrollTarget};
+///
+/// let aria = ScrollAria::new(&ScrollTarget::id("comments"), true, false);
+/// assert_eq!(aria.label, "Scroll to comments");
+/// assert_eq!(aria.controls.as_deref(), Some("comments"));
+/// assert!(!aria.hidden && !aria.busy);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ScrollAria {
+    /// Accessible name for `aria-label`, derived from the target.
+    pub label: String,
+    /// ID of the target element for `aria-controls`, when the target is an ID.
+    pub controls: Option<String>,
+    /// Value for `aria-hidden`: `true` while the trigger should be hidden.
+    pub hidden: bool,
+    /// Value for `aria-busy`: `true` while a scroll is pending or running.
+    pub busy: bool,
+}
+
+impl ScrollAria {
+    /// Describes a trigger that scrolls to `target`.
+    pub fn new(target: &ScrollTarget, visible: bool, is_scrolling: bool) -> Self {
+        let label = match target {
+            ScrollTarget::Position { top, left } if *top == 0.0 && *left == 0.0 => {
+                "Scroll to top".to_string()
+            }
+            ScrollTarget::Bottom => "Scroll to bottom".to_string(),
+            ScrollTarget::Id(id) if !id.is_empty() => format!("Scroll to {id}"),
+            ScrollTarget::By { dy, .. } if *dy < 0.0 => "Scroll up".to_string(),
+            ScrollTarget::By { dy, .. } if *dy > 0.0 => "Scroll down".to_string(),
+            _ => "Scroll".to_string(),
+        };
+        let controls = match target {
+            ScrollTarget::Id(id) if !id.is_empty() => Some(id.clone()),
+            _ => None,
+        };
+        ScrollAria {
+            label,
+            controls,
+            hidden: !visible,
+            busy: is_scrolling,
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use crate::common::hub::{self, Subscription};
+use crate::common::{window, NodeHandle, ScrollError};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{AddEventListenerOptions, Element, Event, EventTarget, ScrollToOptions, Window};
+
+/// The scrollable area a `Scroll` component drives and watches.
+///
+/// Defaults to the browser window. Any other variant points at an element with
+/// `overflow: auto` or `overflow: scroll`, such as a sidebar, a panel or a
+/// modal body.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::ScrollContainer;
+///
+/// assert_eq!(ScrollContainer::default(), ScrollContainer::Window);
+/// let sidebar = ScrollContainer::id("sidebar");
+/// let modal = ScrollContainer::selector(".modal-body");
+/// # let _ = (sidebar, modal);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum ScrollContainer {
+    /// The browser window.
+    #[default]
+    Window,
+    /// The element with the given `id` attribute.
+    Id(String),
+    /// The first element matching a CSS selector.
+    Selector(String),
+    /// An element obtained from a framework node reference.
+    Node(NodeHandle),
+}
+
+impl ScrollContainer {
+    /// Uses the element with the given `id` attribute as the container.
+    pub fn id(id: impl Into<String>) -> Self {
+        ScrollContainer::Id(id.into())
+    }
+
+    /// Uses the first element matching a CSS selector as the container.
+    pub fn selector(selector: impl Into<String>) -> Self {
+        ScrollContainer::Selector(selector.into())
+    }
+
+    /// Uses an element resolved lazily from a node reference as the container.
+    pub fn node(resolve: impl Fn() -> Option<Element> + 'static) -> Self {
+        ScrollContainer::Node(NodeHandle::new(resolve))
+    }
+
+    /// Looks up the container in the current document.
+    ///
+    /// Returns `None` when there is no window or the element is not mounted.
+    pub fn resolve(&self) -> Option<ScrollRoot> {
+        self.try_resolve().ok()
+    }
+
+    /// Looks up the container in the current document, reporting why it could
+    /// not be found.
+    pub fn try_resolve(&self) -> Result<ScrollRoot, ScrollError> {
+        let window = window().ok_or(ScrollError::NoWindow)?;
+        let document = || window.document().ok_or(ScrollError::NoDocument);
+        let element = match self {
+            ScrollContainer::Window => return Ok(ScrollRoot::Window(window)),
+            ScrollContainer::Id(id) => document()?.get_element_by_id(id),
+            ScrollContainer::Selector(selector) => document()?
+                .query_selector(selector)
+                .map_err(|_| ScrollError::InvalidSelector(selector.clone()))?,
+            ScrollContainer::Node(node) => node.get(),
+        };
+        element.map(ScrollRoot::Element).ok_or_else(|| {
+            ScrollError::ContainerNotFound(match self {
+                ScrollContainer::Id(id) => format!("#{id}"),
+                ScrollContainer::Selector(selector) => selector.clone(),
+                _ => "node".to_string(),
+            })
+        })
+    }
+}
+
+/// A resolved [`ScrollContainer`]: either the window or a scrollable element.
+#[derive(Clone, Debug)]
+pub enum ScrollRoot {
+    Window(Window),
+    Element(Element),
+}
+
+impl ScrollRoot {
+    /// Current vertical scroll position in pixels.
+    pub fn scroll_top(&self) -> f64 {
+        match self {
+            ScrollRoot::Window(window) => window.scroll_y().unwrap_or(0.0),
+            ScrollRoot::Element(element) => f64::from(element.scroll_top()),
+        }
+    }
+
+    /// Current horizontal scroll position in pixels.
+    pub fn scroll_left(&self) -> f64 {
+        match self {
+            ScrollRoot::Window(window) => window.scroll_x().unwrap_or(0.0),
+            ScrollRoot::Element(element) => f64::from(element.scroll_left()),
+        }
+    }
+
+    /// The largest vertical scroll position the root can reach.
+    pub fn max_scroll_top(&self) -> f64 {
+        let scroll_height = match self {
+            ScrollRoot::Window(window) => window
+                .document()
+                .and_then(|document| document.document_element())
+                .map_or(0, |root| root.scroll_height()),
+            ScrollRoot::Element(element) => element.scroll_height(),
+        };
+        (f64::from(scroll_height) - self.viewport_height()).max(0.0)
+    }
+
+    /// The largest horizontal scroll position the root can reach.
+    ///
+    /// Unlike the window's inner width, the client width of the document
+    /// excludes a vertical scrollbar, which would otherwise hide part of the
+    /// reachable range.
+    pub fn max_scroll_left(&self) -> f64 {
+        self.scrolling_element().map_or(0.0, |element| {
+            f64::from(element.scroll_width() - element.client_width()).max(0.0)
+        })
+    }
+
+    /// Height in pixels of the root's visible area.
+    pub fn viewport_height(&self) -> f64 {
+        match self {
+            ScrollRoot::Window(window) => window
+                .inner_height()
+                .ok()
+                .and_then(|height| height.as_f64())
+                .unwrap_or(0.0),
+            ScrollRoot::Element(element) => f64::from(element.client_height()),
+        }
+    }
+
+    /// Width in pixels of the root's visible area, excluding a vertical
+    /// scrollbar.
+    pub fn viewport_width(&self) -> f64 {
+        self.scrolling_element()
+            .map_or(0.0, |element| f64::from(element.client_width()))
+    }
+
+    /// Distance from the top and left of the browser viewport to the top and
+    /// left of the root's visible area. Always zero for the window.
+    pub fn viewport_origin(&self) -> (f64, f64) {
+        match self {
+            ScrollRoot::Window(_) => (0.0, 0.0),
+            ScrollRoot::Element(element) => {
+                let rect = element.get_bounding_client_rect();
+                (rect.top(), rect.left())
+            }
+        }
+    }
+
+    /// Scrolls the root with the given options.
+    pub fn scroll_with_options(&self, options: &ScrollToOptions) {
+        match self {
+            ScrollRoot::Window(window) => window.scroll_with_scroll_to_options(options),
+            ScrollRoot::Element(element) => element.scroll_with_scroll_to_options(options),
+        }
+    }
+
+    /// Jumps the root to `(top, left)`.
+    pub fn scroll_to(&self, top: f64, left: f64) {
+        match self {
+            ScrollRoot::Window(window) => window.scroll_to_with_x_and_y(left, top),
+            ScrollRoot::Element(element) => element.scroll_to_with_x_and_y(left, top),
+        }
+    }
+
+    /// The event target that receives the root's `scroll` events.
+    pub fn event_target(&self) -> &EventTarget {
+        match self {
+            ScrollRoot::Window(window) => window.as_ref(),
+            ScrollRoot::Element(element) => element.as_ref(),
+        }
+    }
+
+    /// The element whose widths describe the root: the document element for
+    /// the window.
+    fn scrolling_element(&self) -> Option<Element> {
+        match self {
+            ScrollRoot::Window(window) => window
+                .document()
+                .and_then(|document| document.document_element()),
+            ScrollRoot::Element(element) => Some(element.clone()),
+        }
+    }
+
+    /// Calls `callback` after the root has scrolled, at most once per animation
+    /// frame, until the returned listener is dropped.
+    ///
+    /// Every subscriber on the same root shares one passive `scroll` listener
+    /// and runs in the same frame, so layout is read in a single batch no
+    /// matter how many components watch the root.
+    pub fn on_scroll(&self, callback: impl FnMut() + 'static) -> ScrollListener {
+        hub::subscribe(self.event_target(), callback)
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+thread_local! {
+    static LIVE_LISTENERS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Number of DOM listeners added by this crate that have not been removed.
+#[cfg(all(test, target_arch = "wasm32"))]
+pub(crate) fn live_listeners() -> usize {
+    LIVE_LISTENERS.with(|live| live.get())
+}
+
+/// Calls `callback` on every `event` dispatched to `target` until the returned
+/// listener is dropped.
+pub(crate) fn listen(
+    target: &EventTarget,
+    event: &'static str,
+    mut callback: impl FnMut() + 'static,
+) -> ScrollListener {
+    listen_event(target, event, move |_| callback())
+}
+
+/// Like [`listen`], but hands the event itself to `callback`.
+pub(crate) fn listen_event(
+    target: &EventTarget,
+    event: &'static str,
+    callback: impl FnMut(Event) + 'static,
+) -> ScrollListener {
+    let closure = Closure::<dyn FnMut(Event)>::new(callback);
+    let _ = target.add_event_listener_with_callback(event, closure.as_ref().unchecked_ref());
+    #[cfg(all(test, target_arch = "wasm32"))]
+    LIVE_LISTENERS.with(|live| live.set(live.get() + 1));
+    ScrollListener(Listener::Event {
+        target: target.clone(),
+        event,
+        closure,
+    })
+}
+
+/// Like [`listen`], but promises the browser never to call `preventDefault`,
+/// so scrolling is not held up by the listener.
+pub(crate) fn listen_passive(
+    target: &EventTarget,
+    event: &'static str,
+    mut callback: impl FnMut() + 'static,
+) -> ScrollListener {
+    let closure = Closure::<dyn FnMut(Event)>::new(move |_| callback());
+    let options = AddEventListenerOptions::new();
+    options.set_passive(true);
+    let _ = target.add_event_listener_with_callback_and_add_event_listener_options(
+        event,
+        closure.as_ref().unchecked_ref(),
+        &options,
+    );
+    #[cfg(all(test, target_arch = "wasm32"))]
+    LIVE_LISTENERS.with(|live| live.set(live.get() + 1));
+    ScrollListener(Listener::Event {
+        target: target.clone(),
+        event,
+        closure,
+    })
+}
+
+/// An event listener, usually for `scroll` events, that is removed when dropped.
+pub struct ScrollListener(Listener);
+
+enum Listener {
+    Event {
+        target: EventTarget,
+        event: &'static str,
+        closure: Closure<dyn FnMut(Event)>,
+    },
+    Shared {
+        _subscription: Subscription,
+    },
+}
+
+impl ScrollListener {
+    pub(crate) fn subscription(subscription: Subscription) -> Self {
+        ScrollListener(Listener::Shared {
+            _subscription: subscription,
+        })
+    }
+}
+
+impl Drop for ScrollListener {
+    fn drop(&mut self) {
+        if let Listener::Event {
+            target,
+            event,
+            closure,
+        } = &self.0
+        {
+            let _ =
+                target.remove_event_listener_with_callback(event, closure.as_ref().unchecked_ref());
+            #[cfg(all(test, target_arch = "wasm32"))]
+            LIVE_LISTENERS.with(|live| live.set(live.get() - 1));
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use crate::common::{
+    Behavior, HashMode, ReducedMotion, ScrollAlign, ScrollContainer, ScrollEngine, ScrollError,
+    ScrollRequest, ScrollTarget, ScrollTask,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Configuration shared by every scroll a [`ScrollController`] performs.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScrollOptions {
+    /// Scrollable area to drive. Defaults to the window.
+    pub container: ScrollContainer,
+
+    /// Offset in pixels added to element and position targets, e.g. `-64.0`
+    /// for a fixed header. Defaults to `0.0`.
+    pub offset: f64,
+
+    /// How scrolls are performed. Defaults to [`Behavior::Smooth`].
+    pub behavior: Behavior,
+
+    /// How the URL is updated when scrolling to an element with an ID.
+    /// Defaults to [`HashMode::Push`].
+    pub update_hash: HashMode,
+
+    /// Delay before each scroll starts, in milliseconds. Defaults to `0`.
+    pub delay_ms: u32,
+
+    /// Whether to move keyboard focus to element targets once a scroll has
+    /// finished, so keyboard and screen-reader users land where the page
+    /// scrolled to. See [`ScrollTarget::focus`]. Defaults to `false`.
+    pub focus_target: bool,
+
+    /// Whether smooth and animated scrolls become instant when the user
+    /// prefers reduced motion. Defaults to [`ReducedMotion::System`].
+    pub reduced_motion: ReducedMotion,
+}
+
+impl Default for ScrollOptions {
+    fn default() -> Self {
+        Self {
+            container: ScrollContainer::Window,
+            offset: 0.0,
+            behavior: Behavior::Smooth,
+            update_hash: HashMode::Push,
+            delay_ms: 0,
+            focus_target: false,
+            reduced_motion: ReducedMotion::System,
+        }
+    }
+}
+
+/// Scrolls from your own code, e.g. after a form submit or on a keyboard
+/// shortcut.
+///
+/// A controller performs scrolls exactly like the `Scroll` component does:
+/// the same engine, offset and URL handling, and the same `on_begin`,
+/// `on_end`, `on_cancel` and `on_error` callbacks. Only one scroll runs at a
+/// time; starting a new one cancels the previous one. Every scroll returns
+/// its [`ScrollTask`], whose [`ScrollTask::finished`] future resolves once the
+/// scroll is over. Cloning a controller yields another handle to the same one.
+///
+/// The framework adapters expose it through their `use_scroll` hooks, which
+/// keep the options up to date and stop the running scroll on unmount.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use scroll_rs::{ScrollAlign, ScrollController, ScrollOptions, ScrollTarget};
+///
+/// let controller = ScrollController::new(ScrollOptions { offset: -64.0, ..Default::default() });
+/// controller.on_end(|| log("arrived"));
+/// controller.scroll_into_view(ScrollTarget::id("errors"), ScrollAlign::Center);
+/// # fn log(_: &str) {}
+/// ```
+#[derive(Clone)]
+pub struct ScrollController(Rc<ControllerState>);
+
+struct ControllerState {
+    options: RefCell<ScrollOptions>,
+    handlers: RefCell<Handlers>,
+    task: RefCell<Option<ScrollTask>>,
+}
+
+#[derive(Clone)]
+struct Handlers {
+    on_begin: Rc<dyn Fn()>,
+    on_end: Rc<dyn Fn()>,
+    on_cancel: Rc<dyn Fn()>,
+    on_error: Rc<dyn Fn(ScrollError)>,
+}
+
+impl ScrollController {
+    /// Creates a controller that scrolls with `options`.
+    pub fn new(options: ScrollOptions) -> Self {
+        ScrollController(Rc::new(ControllerState {
+            options: RefCell::new(options),
+            handlers: RefCell::new(Handlers {
+                on_begin: Rc::new(|| {}),
+                on_end: Rc::new(|| {}),
+                on_cancel: Rc::new(|| {}),
+                on_error: Rc::new(|_| {}),
+            }),
+            task: RefCell::new(None),
+        }))
+    }
+
+    /// Replaces the options used by the next scroll.
+    pub fn set_options(&self, options: ScrollOptions) {
+        *self.0.options.borrow_mut() = options;
+    }
+
+    /// Calls `callback` when a scroll begins, after its delay.
+    pub fn on_begin(&self, callback: impl Fn() + 'static) -> &Self {
+        self.0.handlers.borrow_mut().on_begin = Rc::new(callback);
+        self
+    }
+
+    /// Calls `callback` once a scroll has actually finished.
+    pub fn on_end(&self, callback: impl Fn() + 'static) -> &Self {
+        self.0.handlers.borrow_mut().on_end = Rc::new(callback);
+        self
+    }
+
+    /// Calls `callback` when a pending or running scroll is cancelled.
+    pub fn on_cancel(&self, callback: impl Fn() + 'static) -> &Self {
+        self.0.handlers.borrow_mut().on_cancel = Rc::new(callback);
+        self
+    }
+
+    /// Calls `callback` when a scroll cannot be carried out.
+    pub fn on_error(&self, callback: impl Fn(ScrollError) + 'static) -> &Self {
+        self.0.handlers.borrow_mut().on_error = Rc::new(callback);
+        self
+    }
+
+    /// Scrolls to `target`, applying the configured offset.
+    pub fn scroll_to(&self, target: impl Into<ScrollTarget>) -> ScrollTask {
+        self.scroll(target.into(), ScrollAlign::Start)
+    }
+
+    /// Scrolls by `dx` and `dy` pixels from the current position.
+    pub fn scroll_by(&self, dx: f64, dy: f64) -> ScrollTask {
+        self.scroll(ScrollTarget::By { dx, dy }, ScrollAlign::Start)
+    }
+
+    /// Scrolls the element behind `target` into view, placing it according
+    /// to `align`.
+    pub fn scroll_into_view(
+        &self,
+        target: impl Into<ScrollTarget>,
+        align: ScrollAlign,
+    ) -> ScrollTask {
+        self.scroll(target.into(), align)
+    }
+
+    /// Scrolls to the top of the container. The offset is not applied.
+    pub fn scroll_to_top(&self) -> ScrollTask {
+        self.run(ScrollTarget::default(), 0.0, ScrollAlign::Start)
+    }
+
+    /// Scrolls to the bottom of the container. The offset is not applied.
+    pub fn scroll_to_bottom(&self) -> ScrollTask {
+        self.run(ScrollTarget::Bottom, 0.0, ScrollAlign::Start)
+    }
+
+    /// Cancels the pending or running scroll, calling `on_cancel`.
+    ///
+    /// Returns `false`, and does nothing, when no scroll is in progress.
+    pub fn stop(&self) -> bool {
+        let task = self.0.task.borrow_mut().take();
+        task.is_some_and(|task| task.cancel())
+    }
+
+    /// Whether a scroll is pending or running.
+    pub fn is_scrolling(&self) -> bool {
+        self.0
+            .task
+            .borrow()
+            .as_ref()
+            .is_some_and(ScrollTask::is_running)
+    }
+
+    fn scroll(&self, target: ScrollTarget, align: ScrollAlign) -> ScrollTask {
+        let offset = self.0.options.borrow().offset;
+        self.run(target, offset, align)
+    }
+
+    fn run(&self, target: ScrollTarget, offset: f64, align: ScrollAlign) -> ScrollTask {
+        self.stop();
+
+        let options = self.0.options.borrow().clone();
+        let handlers = self.0.handlers.borrow().clone();
+        let focus = options.focus_target.then(|| target.clone());
+        let request = ScrollRequest {
+            target,
+            offset,
+            behavior: options.behavior,
+            update_hash: options.update_hash,
+            align,
+            reduced_motion: options.reduced_motion,
+        };
+        let (on_end, on_cancel) = (handlers.on_end.clone(), handlers.on_cancel.clone());
+        let task = ScrollTask::new(
+            move || {
+                if let Some(target) = focus {
+                    target.focus();
+                }
+                on_end();
+            },
+            move || on_cancel(),
+        );
+        let container = options.container;
+        task.start_after(options.delay_ms, move |task| {
+            (handlers.on_begin)();
+            let result = match ScrollEngine::with_container(&container) {
+                Ok(engine) => engine.run(&request, task),
+                Err(error) => {
+                    task.fail(error.clone());
+                    Err(error)
+                }
+            };
+            if let Err(error) = result {
+                (handlers.on_error)(error);
+            }
+        });
+        *self.0.task.borrow_mut() = Some(task.clone());
+        task
+    }
+}
@@ -0,0 +1,165 @@
+use std::f64::consts::PI;
+use std::fmt;
+use std::rc::Rc;
+
+const BACK_C1: f64 = 1.70158;
+const BACK_C2: f64 = BACK_C1 * 1.525;
+const BACK_C3: f64 = BACK_C1 + 1.0;
+
+/// Timing curve used by [`Behavior::Animated`](crate::Behavior::Animated).
+///
+/// An easing maps the elapsed fraction of an animation (`0.0..=1.0`) to the
+/// fraction of the distance that should have been covered at that point.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::Easing;
+///
+/// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+/// assert_eq!(Easing::EaseInOutCubic.apply(0.5), 0.5);
+/// assert_eq!(Easing::EaseOutExpo.apply(1.0), 1.0);
+///
+/// let steps = Easing::custom(|t| (t * 4.0).floor() / 4.0);
+/// assert_eq!(steps.apply(0.6), 0.5);
+/// ```
+#[derive(Clone, Default)]
+pub enum Easing {
+    Linear,
+    EaseInSine,
+    EaseOutSine,
+    EaseInOutSine,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    #[default]
+    EaseInOutCubic,
+    EaseInQuart,
+    EaseOutQuart,
+    EaseInOutQuart,
+    EaseInExpo,
+    EaseOutExpo,
+    EaseInOutExpo,
+    EaseInBack,
+    EaseOutBack,
+    EaseInOutBack,
+    /// A user-supplied curve. Build it with [`Easing::custom`].
+    Custom(Rc<dyn Fn(f64) -> f64>),
+}
+
+impl Easing {
+    /// Wraps a closure as an easing curve.
+    pub fn custom(f: impl Fn(f64) -> f64 + 'static) -> Self {
+        Easing::Custom(Rc::new(f))
+    }
+
+    /// Evaluates the curve at `t`, which is clamped to `0.0..=1.0`.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInSine => 1.0 - (t * PI / 2.0).cos(),
+            Easing::EaseOutSine => (t * PI / 2.0).sin(),
+            Easing::EaseInOutSine => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => 1.0 - (1.0 - t).powi(2),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::EaseInCubic => t.powi(3),
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t.powi(3)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::EaseInQuart => t.powi(4),
+            Easing::EaseOutQuart => 1.0 - (1.0 - t).powi(4),
+            Easing::EaseInOutQuart => {
+                if t < 0.5 {
+                    8.0 * t.powi(4)
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(4) / 2.0
+                }
+            }
+            Easing::EaseInExpo => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    2f64.powf(10.0 * t - 10.0)
+                }
+            }
+            Easing::EaseOutExpo => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - 2f64.powf(-10.0 * t)
+                }
+            }
+            Easing::EaseInOutExpo => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t < 0.5 {
+                    2f64.powf(20.0 * t - 10.0) / 2.0
+                } else {
+                    (2.0 - 2f64.powf(-20.0 * t + 10.0)) / 2.0
+                }
+            }
+            Easing::EaseInBack => BACK_C3 * t.powi(3) - BACK_C1 * t * t,
+            Easing::EaseOutBack => 1.0 + BACK_C3 * (t - 1.0).powi(3) + BACK_C1 * (t - 1.0).powi(2),
+            Easing::EaseInOutBack => {
+                if t < 0.5 {
+                    (2.0 * t).powi(2) * ((BACK_C2 + 1.0) * 2.0 * t - BACK_C2) / 2.0
+                } else {
+                    ((2.0 * t - 2.0).powi(2) * ((BACK_C2 + 1.0) * (2.0 * t - 2.0) + BACK_C2) + 2.0)
+                        / 2.0
+                }
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Easing::Custom(a), Easing::Custom(b)) => Rc::ptr_eq(a, b),
+            (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+        }
+    }
+}
+
+impl fmt::Debug for Easing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Easing::Linear => f.write_str("Linear"),
+            Easing::EaseInSine => f.write_str("EaseInSine"),
+            Easing::EaseOutSine => f.write_str("EaseOutSine"),
+            Easing::EaseInOutSine => f.write_str("EaseInOutSine"),
+            Easing::EaseInQuad => f.write_str("EaseInQuad"),
+            Easing::EaseOutQuad => f.write_str("EaseOutQuad"),
+            Easing::EaseInOutQuad => f.write_str("EaseInOutQuad"),
+            Easing::EaseInCubic => f.write_str("EaseInCubic"),
+            Easing::EaseOutCubic => f.write_str("EaseOutCubic"),
+            Easing::EaseInOutCubic => f.write_str("EaseInOutCubic"),
+            Easing::EaseInQuart => f.write_str("EaseInQuart"),
+            Easing::EaseOutQuart => f.write_str("EaseOutQuart"),
+            Easing::EaseInOutQuart => f.write_str("EaseInOutQuart"),
+            Easing::EaseInExpo => f.write_str("EaseInExpo"),
+            Easing::EaseOutExpo => f.write_str("EaseOutExpo"),
+            Easing::EaseInOutExpo => f.write_str("EaseInOutExpo"),
+            Easing::EaseInBack => f.write_str("EaseInBack"),
+            Easing::EaseOutBack => f.write_str("EaseOutBack"),
+            Easing::EaseInOutBack => f.write_str("EaseInOutBack"),
+            Easing::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use crate::common::container::listen;
+use crate::common::frame::request_frames;
+use crate::common::{
+    window, Behavior, Easing, HashMode, ReducedMotion, ScrollContainer, ScrollError, ScrollOutcome,
+    ScrollRoot, ScrollTarget, ScrollTask,
+};
+use wasm_bindgen::JsValue;
+use web_sys::{Element, ScrollToOptions, Window};
+
+/// Consecutive frames without movement after which a scroll that has not
+/// reached its destination is considered finished, e.g. because the user took
+/// over.
+const SETTLE_FRAMES: u32 = 10;
+
+/// Upper bound on how long a scroll is watched before it is reported as
+/// finished regardless of its position.
+const SETTLE_TIMEOUT_MS: f64 = 3000.0;
+
+/// A single scroll action, independent of any UI framework.
+///
+/// The Yew, Dioxus and Leptos `Scroll` components translate their props into a
+/// `ScrollRequest` and hand it to a [`ScrollEngine`], so every adapter resolves
+/// targets, applies offsets and updates the URL hash in exactly the same way.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScrollRequest {
+    /// Where to scroll to.
+    pub target: ScrollTarget,
+
+    /// Additional offset in pixels added to the resolved vertical position.
+    ///
+    /// Ignored for [`ScrollTarget::By`], which is already relative.
+    pub offset: f64,
+
+    /// How the browser should perform the scroll.
+    pub behavior: Behavior,
+
+    /// How the target element's ID is written to the URL once the scroll is
+    /// issued. Targets without an element ID leave the URL untouched.
+    pub update_hash: HashMode,
+
+    /// Where an element target lands in the visible area of the root.
+    ///
+    /// Ignored for targets that are not elements.
+    pub align: ScrollAlign,
+
+    /// Whether smooth and animated scrolls jump straight to the destination
+    /// when the user prefers reduced motion.
+    pub reduced_motion: ReducedMotion,
+}
+
+/// Where an element lands in the visible area once scrolled to.
+///
+/// Mirrors the `block` option of the DOM's `scrollIntoView`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ScrollAlign {
+    /// The element's top lines up with the top of the visible area.
+    #[default]
+    Start,
+    /// The element is centered in the visible area.
+    Center,
+    /// The element's bottom lines up with the bottom of the visible area.
+    End,
+    /// Scroll as little as possible to bring the element fully into view, and
+    /// not at all when it already is.
+    Nearest,
+}
+
+impl Default for ScrollRequest {
+    fn default() -> Self {
+        Self {
+            target: ScrollTarget::default(),
+            offset: 0.0,
+            behavior: Behavior::Smooth,
+            update_hash: HashMode::Push,
+            align: ScrollAlign::Start,
+            reduced_motion: ReducedMotion::System,
+        }
+    }
+}
+
+/// Performs [`ScrollRequest`]s against a scroll root.
+///
+/// The engine owns target resolution, offset math, behavior mapping and hash
+/// updates. Framework adapters should not talk to `ScrollToOptions` directly.
+/// By default it drives the browser window; use [`ScrollEngine::with_container`]
+/// to drive a scrollable element instead.
+pub struct ScrollEngine {
+    window: Window,
+    root: ScrollRoot,
+}
+
+impl ScrollEngine {
+    /// Creates an engine bound to the current browser window.
+    ///
+    /// Fails with [`ScrollError::NoWindow`] outside the browser, e.g. during
+    /// server-side rendering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scroll_rs::{ScrollEngine, ScrollError};
+    ///
+    /// // Natively, e.g. while rendering on the server, there is no window.
+    /// # if !cfg!(target_arch = "wasm32") {
+    /// assert_eq!(ScrollEngine::new().err(), Some(ScrollError::NoWindow));
+    /// # }
+    /// ```
+    pub fn new() -> Result<Self, ScrollError> {
+        let window = window().ok_or(ScrollError::NoWindow)?;
+        Ok(Self {
+            root: ScrollRoot::Window(window.clone()),
+            window,
+        })
+    }
+
+    /// Creates an engine bound to `container`.
+    ///
+    /// Fails when there is no window or the container element is not mounted.
+    pub fn with_container(container: &ScrollContainer) -> Result<Self, ScrollError> {
+        Ok(Self {
+            window: window().ok_or(ScrollError::NoWindow)?,
+            root: container.try_resolve()?,
+        })
+    }
+
+    /// The window or element this engine scrolls.
+    pub fn root(&self) -> &ScrollRoot {
+        &self.root
+    }
+
+    /// Scrolls according to `request` and updates the URL hash if requested.
+    ///
+    /// Returns the [`ScrollTask`] tracking the scroll, which can be used to
+    /// cancel it. See [`ScrollEngine::run`] for when this fails.
+    pub fn scroll(&self, request: &ScrollRequest) -> Result<ScrollTask, ScrollError> {
+        let task = ScrollTask::new(|| {}, || {});
+        self.run(request, &task).map(|()| task)
+    }
+
+    /// Scrolls according to `request` and waits until the scroll has finished
+    /// or been cancelled.
+    ///
+    /// The outcome reports where the root ended up and whether the
+    /// destination was reached. Fails right away when the target cannot be
+    /// resolved. When only the URL update fails, the [`ScrollError::History`]
+    /// is returned once the scroll is over.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use scroll_rs::{ScrollEngine, ScrollError, ScrollRequest, ScrollTarget};
+    ///
+    /// async fn show_details() -> Result<(), ScrollError> {
+    ///     let request = ScrollRequest { target: ScrollTarget::id("details"), ..Default::default() };
+    ///     let outcome = ScrollEngine::new()?.scroll_to(&request).await?;
+    ///     if outcome.reached {
+    ///         focus_details();
+    ///     }
+    ///     Ok(())
+    /// }
+    /// # fn focus_details() {}
+    /// ```
+    pub async fn scroll_to(&self, request: &ScrollRequest) -> Result<ScrollOutcome, ScrollError> {
+        let task = ScrollTask::new(|| {}, || {});
+        let history = self.run(request, &task);
+        let outcome = task.finished().await?;
+        history.map(|()| outcome)
+    }
+
+    /// Performs `request` as part of `task`, finishing the task once the
+    /// scroll has actually ended.
+    ///
+    /// Animated scrolls finish with their last frame. Native scrolls finish on
+    /// the root's `scrollend` event where the browser supports it, or once the
+    /// position has reached the destination or stopped moving.
+    ///
+    /// When the target cannot be resolved nothing is scrolled, the task fails
+    /// without calling back and the error is returned. When only the
+    /// URL update fails, the scroll still runs to completion and the
+    /// [`ScrollError::History`] is returned.
+    pub fn run(&self, request: &ScrollRequest, task: &ScrollTask) -> Result<(), ScrollError> {
+        let (top, left) = match self.resolve(request) {
+            Ok(position) => position,
+            Err(error) => {
+                task.fail(error.clone());
+                return Err(error);
+            }
+        };
+        task.set_root(self.root.clone());
+        task.set_destination(top, left);
+
+        let behavior = request.reduced_motion.apply(&request.behavior);
+        if let Behavior::Animated {
+            duration_ms,
+            easing,
+        } = behavior
+        {
+            self.animate(top, left, duration_ms, easing, task);
+        } else {
+            let options = ScrollToOptions::new();
+            options.set_top(top);
+            options.set_left(left);
+            options.set_behavior((&behavior).into());
+            self.root.scroll_with_options(&options);
+            self.watch_end(top, left, task);
+        }
+
+        if request.update_hash != HashMode::None {
+            if let Some(element) = request.target.element() {
+                request.update_hash.apply(&element.id())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves `request` into absolute `(top, left)` coordinates within the
+    /// scroll root.
+    ///
+    /// Element targets are measured relative to the root's content rather than
+    /// the viewport, so the result does not depend on the current scroll
+    /// position. Horizontally, the root only moves when the element is not
+    /// already fully in view. Fails when the target element cannot be found.
+    pub fn resolve(&self, request: &ScrollRequest) -> Result<(f64, f64), ScrollError> {
+        let (scroll_top, scroll_left) = (self.scroll_top(), self.scroll_left());
+        let (top, left) = match &request.target {
+            ScrollTarget::Position { top, left } => (*top, *left),
+            ScrollTarget::Bottom => (self.root.max_scroll_top(), scroll_left),
+            ScrollTarget::Percent(percent) => (
+                self.root.max_scroll_top() * percent.clamp(0.0, 100.0) / 100.0,
+                scroll_left,
+            ),
+            ScrollTarget::By { dx, dy } => return Ok((scroll_top + dy, scroll_left + dx)),
+            target => {
+                // Only an empty ID resolves to no element. It names nothing, so
+                // like an unset target it scrolls to the top.
+                let Some(element) = target.try_element()? else {
+                    return Ok((request.offset, 0.0));
+                };
+                let rect = element.get_bounding_client_rect();
+                let (origin_top, origin_left) = self.root.viewport_origin();
+                // Horizontally the element is only brought into view, like the
+                // `nearest` inline alignment of `scrollIntoView`.
+                let left = nearest(
+                    rect.left() - origin_left,
+                    self.root.viewport_width() - rect.width(),
+                )
+                .map_or(scroll_left, |left| {
+                    (left + scroll_left).clamp(0.0, self.root.max_scroll_left())
+                });
+                let top = rect.top() - origin_top;
+                let room = self.root.viewport_height() - rect.height();
+                let top = match request.align {
+                    ScrollAlign::Start => top,
+                    ScrollAlign::Center => top - room / 2.0,
+                    ScrollAlign::End => top - room,
+                    ScrollAlign::Nearest => match nearest(top, room) {
+                        Some(top) => top,
+                        None => return Ok((scroll_top, left)),
+                    },
+                };
+                (top + scroll_top, left)
+            }
+        };
+        Ok((top + request.offset, left))
+    }
+
+    /// Current vertical scroll position of the root.
+    pub fn scroll_top(&self) -> f64 {
+        self.root.scroll_top()
+    }
+
+    /// Current horizontal scroll position of the root.
+    pub fn scroll_left(&self) -> f64 {
+        self.root.scroll_left()
+    }
+
+    /// Whether the root has been scrolled far enough to show a scroll button.
+    ///
+    /// When `show_id` names an element, the button is shown once the scroll
+    /// position passes that element's top. Otherwise `threshold` is used.
+    pub fn is_past(&self, threshold: f64, show_id: &str) -> bool {
+        let scroll_position = self.scroll_top();
+        match self.element_by_id(show_id) {
+            Some(element) => {
+                let (origin_top, _) = self.root.viewport_origin();
+                scroll_position > element.get_bounding_client_rect().top() - origin_top
+            }
+            None => scroll_position > threshold,
+        }
+    }
+
+    /// How far the root, or `element` within it, has been scrolled through,
+    /// from `0.0` to `1.0`.
+    ///
+    /// Without an element this is the root's scroll position relative to its
+    /// maximum. With an element, progress starts when the element's top reaches
+    /// the top of the visible area and completes when its bottom reaches the
+    /// bottom of the visible area.
+    pub fn progress(&self, element: Option<&ScrollTarget>) -> f64 {
+        let Some(target) = element else {
+            let max = self.root.max_scroll_top();
+            return if max > 0.0 {
+                (self.scroll_top() / max).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+        };
+        let Some(element) = target.element() else {
+            return 0.0;
+        };
+        let rect = element.get_bounding_client_rect();
+        let (origin_top, _) = self.root.viewport_origin();
+        let top = rect.top() - origin_top;
+        let distance = rect.height() - self.root.viewport_height();
+        if distance > 0.0 {
+            (-top / distance).clamp(0.0, 1.0)
+        } else if top <= 0.0 {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Drives the root from its current position to `(top, left)` one
+    /// animation frame at a time.
+    fn animate(&self, top: f64, left: f64, duration_ms: u32, easing: Easing, task: &ScrollTask) {
+        let root = self.root.clone();
+        let (from_top, from_left) = (self.scroll_top(), self.scroll_left());
+        let duration = f64::from(duration_ms);
+        let mut start = None;
+        let this = task.clone();
+
+        task.set_frames(request_frames(move |now| {
+            let start = *start.get_or_insert(now);
+            let progress = if duration > 0.0 {
+                ((now - start) / duration).min(1.0)
+            } else {
+                1.0
+            };
+            let eased = easing.apply(progress);
+            root.scroll_to(
+                from_top + (top - from_top) * eased,
+                from_left + (left - from_left) * eased,
+            );
+            if progress >= 1.0 {
+                this.finish();
+            }
+            progress < 1.0
+        }));
+    }
+
+    /// Finishes `task` once a native scroll towards `(top, left)` is over.
+    ///
+    /// Whichever comes first wins: a `scrollend` event, the root reaching the
+    /// destination, the root standing still for [`SETTLE_FRAMES`] frames, or
+    /// [`SETTLE_TIMEOUT_MS`] passing.
+    fn watch_end(&self, top: f64, left: f64, task: &ScrollTask) {
+        let root = self.root.clone();
+        let top = top.clamp(0.0, root.max_scroll_top());
+        let left = left.clamp(0.0, root.max_scroll_left());
+
+        if js_sys::Reflect::has(&self.window, &JsValue::from_str("onscrollend")).unwrap_or(false) {
+            let this = task.clone();
+            task.add_listener(listen(root.event_target(), "scrollend", move || {
+                this.finish()
+            }));
+        }
+
+        let this = task.clone();
+        let mut start = None;
+        let mut last = (root.scroll_top(), root.scroll_left());
+        let mut still = 0;
+        task.set_frames(request_frames(move |now| {
+            let start = *start.get_or_insert(now);
+            let position = (root.scroll_top(), root.scroll_left());
+            let arrived = (position.0 - top).abs() < 1.0 && (position.1 - left).abs() < 1.0;
+            still = if position == last { still + 1 } else { 0 };
+            last = position;
+            if arrived || still >= SETTLE_FRAMES || now - start >= SETTLE_TIMEOUT_MS {
+                this.finish();
+                return false;
+            }
+            true
+        }));
+    }
+
+    fn element_by_id(&self, id: &str) -> Option<Element> {
+        if id.is_empty() {
+            return None;
+        }
+        self.window.document()?.get_element_by_id(id)
+    }
+}
+
+/// How far to move along one axis to bring an element fully into view, given
+/// its `start` relative to the visible area and the `room` left around it.
+///
+/// Returns `None` when it is already fully in view. Elements larger than the
+/// visible area are aligned to their start.
+fn nearest(start: f64, room: f64) -> Option<f64> {
+    if start >= 0.0 && room >= start {
+        None
+    } else if start < 0.0 || room < 0.0 {
+        Some(start)
+    } else {
+        Some(start - room)
+    }
+}
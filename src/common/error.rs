@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// Why a scroll could not be carried out.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::ScrollError;
+///
+/// let error = ScrollError::TargetNotFound("#install".to_string());
+/// assert_eq!(error.to_string(), "scroll target `#install` not found");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum ScrollError {
+    /// There is no browser window, e.g. during server-side rendering.
+    NoWindow,
+    /// The window has no document.
+    NoDocument,
+    /// The target element is not in the document.
+    TargetNotFound(String),
+    /// The container element is not in the document.
+    ContainerNotFound(String),
+    /// A CSS selector could not be parsed.
+    InvalidSelector(String),
+    /// The browser refused to update the history, e.g. in a sandboxed iframe.
+    History(String),
+}
+
+impl fmt::Display for ScrollError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScrollError::NoWindow => f.write_str("window not available"),
+            ScrollError::NoDocument => f.write_str("document not available"),
+            ScrollError::TargetNotFound(target) => write!(f, "scroll target `{target}` not found"),
+            ScrollError::ContainerNotFound(container) => {
+                write!(f, "scroll container `{container}` not found")
+            }
+            ScrollError::InvalidSelector(selector) => write!(f, "invalid selector `{selector}`"),
+            ScrollError::History(message) => write!(f, "history update failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ScrollError {}
@@ -0,0 +1,74 @@
+use crate::common::window;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+type FrameCallback = Closure<dyn FnMut(f64)>;
+
+/// Runs `step` once per animation frame.
+///
+/// The step closure receives the frame timestamp in milliseconds and returns
+/// `true` to be called again on the next frame. The loop keeps itself alive
+/// until the step returns `false` or the returned handle is cancelled.
+pub(crate) fn request_frames(mut step: impl FnMut(f64) -> bool + 'static) -> FrameHandle {
+    let frames = Rc::new(Frames {
+        closure: RefCell::new(None),
+        id: Cell::new(None),
+    });
+    if window().is_none() {
+        return FrameHandle(frames);
+    }
+
+    let this = frames.clone();
+    *frames.closure.borrow_mut() = Some(Closure::new(move |now: f64| {
+        this.id.set(None);
+        if step(now) {
+            this.request();
+        } else {
+            this.stop();
+        }
+    }));
+    frames.request();
+    FrameHandle(frames)
+}
+
+/// Stops a frame loop started with [`request_frames`].
+///
+/// Dropping the handle leaves the loop running.
+pub(crate) struct FrameHandle(Rc<Frames>);
+
+impl FrameHandle {
+    pub(crate) fn cancel(&self) {
+        self.0.stop();
+    }
+}
+
+struct Frames {
+    closure: RefCell<Option<FrameCallback>>,
+    id: Cell<Option<i32>>,
+}
+
+impl Frames {
+    fn request(&self) {
+        let Some(window) = window() else {
+            return self.stop();
+        };
+        if let Some(closure) = self.closure.borrow().as_ref() {
+            self.id.set(
+                window
+                    .request_animation_frame(closure.as_ref().unchecked_ref())
+                    .ok(),
+            );
+        }
+    }
+
+    fn stop(&self) {
+        if let (Some(id), Some(window)) = (self.id.take(), window()) {
+            let _ = window.cancel_animation_frame(id);
+        }
+        // Dropping the closure from inside its own invocation is fine:
+        // wasm-bindgen defers the destructor until the call returns.
+        self.closure.borrow_mut().take();
+    }
+}
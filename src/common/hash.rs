@@ -0,0 +1,256 @@
+use crate::common::container::listen;
+use crate::common::frame::{request_frames, FrameHandle};
+use crate::common::{
+    window, Behavior, ScrollContainer, ScrollEngine, ScrollError, ScrollListener, ScrollRequest,
+    ScrollTarget,
+};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Url, Window};
+
+/// How a scroll to an element with an ID is reflected in the URL.
+///
+/// Nothing is written when the target has no element ID, or when the URL
+/// already points at the target.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::HashMode;
+///
+/// assert_eq!(HashMode::default(), HashMode::Push);
+/// assert_eq!(HashMode::from(false), HashMode::None);
+/// let section = HashMode::query("section");
+/// let analytics = HashMode::custom(|id| println!("viewed {id}"));
+/// # let _ = (section, analytics);
+/// ```
+#[derive(Clone, Default)]
+pub enum HashMode {
+    /// Leave the URL untouched.
+    None,
+    /// Push `#id` as a new history entry.
+    #[default]
+    Push,
+    /// Replace the current history entry with `#id`, keeping the back stack
+    /// clean.
+    Replace,
+    /// Set the query parameter with this name to the ID, replacing the current
+    /// history entry.
+    Query(String),
+    /// Hand the ID to a callback and leave the URL to it.
+    Custom(Rc<dyn Fn(&str)>),
+}
+
+impl HashMode {
+    /// Sets the query parameter `name` to the target's ID.
+    pub fn query(name: impl Into<String>) -> Self {
+        HashMode::Query(name.into())
+    }
+
+    /// Calls `update` with the target's ID instead of touching the URL.
+    pub fn custom(update: impl Fn(&str) + 'static) -> Self {
+        HashMode::Custom(Rc::new(update))
+    }
+
+    /// Reflects a scroll to the element with the given `id` in the URL.
+    ///
+    /// Fails when the browser refuses the history update, e.g. in a sandboxed
+    /// iframe or when the URL would change origin.
+    pub(crate) fn apply(&self, id: &str) -> Result<(), ScrollError> {
+        if id.is_empty() {
+            return Ok(());
+        }
+        if let HashMode::Custom(update) = self {
+            update(id);
+            return Ok(());
+        }
+        let window = window().ok_or(ScrollError::NoWindow)?;
+        let history = window.history().map_err(history_error)?;
+        let hash = format!("#{id}");
+        match self {
+            HashMode::None | HashMode::Custom(_) => Ok(()),
+            HashMode::Push if hash_id(&window).as_deref() != Some(id) => {
+                history.push_state_with_url(&JsValue::NULL, "", Some(&hash))
+            }
+            HashMode::Push => Ok(()),
+            HashMode::Replace => history.replace_state_with_url(&JsValue::NULL, "", Some(&hash)),
+            HashMode::Query(name) => {
+                let url = window
+                    .location()
+                    .href()
+                    .and_then(|href| Url::new(&href))
+                    .map_err(history_error)?;
+                if url.search_params().get(name).as_deref() == Some(id) {
+                    return Ok(());
+                }
+                url.search_params().set(name, id);
+                history.replace_state_with_url(&JsValue::NULL, "", Some(&url.href()))
+            }
+        }
+        .map_err(history_error)
+    }
+}
+
+/// Turns a rejected history call into a [`ScrollError::History`].
+fn history_error(error: JsValue) -> ScrollError {
+    ScrollError::History(
+        error
+            .dyn_ref::<js_sys::Error>()
+            .map(|error| String::from(error.message()))
+            .or_else(|| error.as_string())
+            .unwrap_or_else(|| format!("{error:?}")),
+    )
+}
+
+impl From<bool> for HashMode {
+    /// `true` pushes the hash, `false` leaves the URL untouched.
+    fn from(update: bool) -> Self {
+        if update {
+            HashMode::Push
+        } else {
+            HashMode::None
+        }
+    }
+}
+
+impl PartialEq for HashMode {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (HashMode::Query(a), HashMode::Query(b)) => a == b,
+            (HashMode::Custom(a), HashMode::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl fmt::Debug for HashMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashMode::None => f.write_str("None"),
+            HashMode::Push => f.write_str("Push"),
+            HashMode::Replace => f.write_str("Replace"),
+            HashMode::Query(name) => f.debug_tuple("Query").field(name).finish(),
+            HashMode::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Configuration for scrolling to the element named by the URL hash.
+#[derive(Clone, PartialEq, Debug)]
+pub struct HashScrollOptions {
+    /// Offset in pixels added to the target's position, e.g. `-64.0` for a
+    /// fixed header. Defaults to `0.0`.
+    pub offset: f64,
+
+    /// How the scroll is performed. Defaults to [`Behavior::Smooth`].
+    pub behavior: Behavior,
+
+    /// Scrollable area the targets live in. Defaults to the window.
+    pub container: ScrollContainer,
+
+    /// How long to wait, in milliseconds, for the target element to be
+    /// rendered before giving up. Defaults to `3000`.
+    pub timeout_ms: u32,
+}
+
+impl Default for HashScrollOptions {
+    fn default() -> Self {
+        Self {
+            offset: 0.0,
+            behavior: Behavior::Smooth,
+            container: ScrollContainer::Window,
+            timeout_ms: 3000,
+        }
+    }
+}
+
+/// Scrolls to the element named by `location.hash`, on start and on every
+/// `hashchange`.
+///
+/// Browsers jump to hash targets on their own, but they know nothing about
+/// fixed headers and give up if the element is rendered later. This scrolls
+/// again with the configured offset and behavior, waiting for the element to
+/// appear if needed. Stops listening when dropped.
+pub struct HashScroll {
+    pending: Rc<RefCell<Option<FrameHandle>>>,
+    _hashchange: ScrollListener,
+}
+
+impl HashScroll {
+    /// Scrolls to the current hash target, if any, and starts listening for
+    /// `hashchange` events.
+    ///
+    /// Returns `None` when there is no window.
+    pub fn start(options: HashScrollOptions) -> Option<Self> {
+        let window = window()?;
+        let pending = Rc::new(RefCell::new(None));
+        let options = Rc::new(options);
+
+        scroll_to_hash(&window, &options, &pending);
+        let hashchange = listen(window.as_ref(), "hashchange", {
+            let window = window.clone();
+            let pending = pending.clone();
+            move || scroll_to_hash(&window, &options, &pending)
+        });
+
+        Some(Self {
+            pending,
+            _hashchange: hashchange,
+        })
+    }
+}
+
+impl Drop for HashScroll {
+    fn drop(&mut self) {
+        if let Some(pending) = self.pending.borrow_mut().take() {
+            pending.cancel();
+        }
+    }
+}
+
+/// Waits for the current hash target to exist, then scrolls to it.
+fn scroll_to_hash(
+    window: &Window,
+    options: &Rc<HashScrollOptions>,
+    pending: &Rc<RefCell<Option<FrameHandle>>>,
+) {
+    if let Some(previous) = pending.borrow_mut().take() {
+        previous.cancel();
+    }
+    let Some(id) = hash_id(window) else {
+        return;
+    };
+
+    let options = options.clone();
+    let timeout = f64::from(options.timeout_ms);
+    let mut start = None;
+    let frames = request_frames(move |now| {
+        let start = *start.get_or_insert(now);
+        let target = ScrollTarget::Id(id.clone());
+        if target.element().is_none() {
+            return now - start < timeout;
+        }
+        if let Ok(engine) = ScrollEngine::with_container(&options.container) {
+            let _ = engine.scroll(&ScrollRequest {
+                target,
+                offset: options.offset,
+                behavior: options.behavior.clone(),
+                update_hash: HashMode::None,
+                ..Default::default()
+            });
+        }
+        false
+    });
+    *pending.borrow_mut() = Some(frames);
+}
+
+/// The decoded element ID in the current URL hash, if there is one.
+pub(crate) fn hash_id(window: &Window) -> Option<String> {
+    let hash = window.location().hash().ok()?;
+    let id = js_sys::decode_uri_component(hash.trim_start_matches('#'))
+        .map(String::from)
+        .ok()?;
+    (!id.is_empty()).then_some(id)
+}
@@ -0,0 +1,131 @@
+use crate::common::container::listen_passive;
+use crate::common::registry::{ScrollSubscribers, ScrollSubscription};
+use crate::common::{window, ScrollListener};
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::EventTarget;
+
+type OnScroll = Rc<RefCell<dyn FnMut()>>;
+
+thread_local! {
+    static HUBS: RefCell<Vec<Rc<ScrollHub>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The single `scroll` listener of one scroll root, shared by every
+/// subscriber on that root.
+///
+/// The listener is passive and only schedules an animation frame. All
+/// subscribers then run back to back in that frame, so their layout reads
+/// happen together, at most once per frame, however many components watch the
+/// same root. The DOM listener is removed together with the last subscriber.
+struct ScrollHub {
+    target: EventTarget,
+    subscribers: ScrollSubscribers<OnScroll>,
+    frame: Closure<dyn FnMut()>,
+    frame_id: Cell<Option<i32>>,
+    listener: RefCell<Option<ScrollListener>>,
+}
+
+/// Number of `on_scroll` subscribers across every scroll root.
+#[cfg(all(test, target_arch = "wasm32"))]
+pub(crate) fn live_subscribers() -> usize {
+    HUBS.with(|hubs| hubs.borrow().iter().map(|hub| hub.subscribers.len()).sum())
+}
+
+/// Calls `callback` at most once per animation frame after `target` has
+/// scrolled, until the returned listener is dropped.
+pub(crate) fn subscribe(target: &EventTarget, callback: impl FnMut() + 'static) -> ScrollListener {
+    let hub = HUBS.with(|hubs| {
+        let mut hubs = hubs.borrow_mut();
+        if let Some(hub) = hubs.iter().find(|hub| hub.target == *target) {
+            return hub.clone();
+        }
+        let hub = ScrollHub::new(target);
+        hubs.push(hub.clone());
+        hub
+    });
+    let subscription = hub.subscribers.subscribe(Rc::new(RefCell::new(callback)));
+    ScrollListener::subscription(Subscription {
+        hub,
+        subscription: Some(subscription),
+    })
+}
+
+impl ScrollHub {
+    fn new(target: &EventTarget) -> Rc<Self> {
+        let hub = Rc::new_cyclic(|this: &Weak<ScrollHub>| ScrollHub {
+            target: target.clone(),
+            subscribers: ScrollSubscribers::new(),
+            frame: Closure::new({
+                let this = this.clone();
+                move || {
+                    if let Some(hub) = this.upgrade() {
+                        hub.flush();
+                    }
+                }
+            }),
+            frame_id: Cell::new(None),
+            listener: RefCell::new(None),
+        });
+        let this = Rc::downgrade(&hub);
+        *hub.listener.borrow_mut() = Some(listen_passive(target, "scroll", move || {
+            if let Some(hub) = this.upgrade() {
+                hub.schedule();
+            }
+        }));
+        hub
+    }
+
+    /// Requests a frame for the subscribers unless one is already pending.
+    fn schedule(&self) {
+        if self.frame_id.get().is_some() {
+            return;
+        }
+        match window() {
+            Some(window) => self.frame_id.set(
+                window
+                    .request_animation_frame(self.frame.as_ref().unchecked_ref())
+                    .ok(),
+            ),
+            None => self.flush(),
+        }
+    }
+
+    /// Runs every subscriber once.
+    fn flush(&self) {
+        self.frame_id.set(None);
+        self.subscribers.for_each(|callback| {
+            // A subscriber that is already running is not re-entered.
+            if let Ok(mut callback) = callback.try_borrow_mut() {
+                (*callback)();
+            }
+        });
+    }
+
+    /// Removes the DOM listener once the last subscriber is gone.
+    fn close_if_idle(self: &Rc<Self>) {
+        if !self.subscribers.is_empty() {
+            return;
+        }
+        if let (Some(frame_id), Some(window)) = (self.frame_id.take(), window()) {
+            let _ = window.cancel_animation_frame(frame_id);
+        }
+        self.listener.borrow_mut().take();
+        let _ = HUBS.try_with(|hubs| hubs.borrow_mut().retain(|hub| !Rc::ptr_eq(hub, self)));
+    }
+}
+
+/// One subscriber of a [`ScrollHub`], removed when dropped.
+pub(crate) struct Subscription {
+    hub: Rc<ScrollHub>,
+    subscription: Option<ScrollSubscription<OnScroll>>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.subscription.take();
+        self.hub.close_if_idle();
+    }
+}
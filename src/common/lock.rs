@@ -0,0 +1,266 @@
+use crate::common::{window, ScrollContainer, ScrollRoot};
+use std::cell::RefCell;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    AddEventListenerOptions, Document, Event, HtmlElement, Node, ScrollBehavior, ScrollToOptions,
+    Window,
+};
+
+/// Configuration for locking the page scroll.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScrollLockOptions {
+    /// Inner scrollable areas that keep scrolling by touch while the page is
+    /// locked, such as the body of a modal. Defaults to none.
+    pub allow: Vec<ScrollContainer>,
+
+    /// Pad the body by the width of the removed scrollbar so the layout does
+    /// not shift. Defaults to `true`.
+    pub reserve_scrollbar: bool,
+}
+
+impl Default for ScrollLockOptions {
+    fn default() -> Self {
+        Self {
+            allow: Vec::new(),
+            reserve_scrollbar: true,
+        }
+    }
+}
+
+/// Prevents the page behind a modal or drawer from scrolling.
+///
+/// Locks are reference counted: the page stays locked while at least one
+/// `ScrollLock` is alive, and the body styles and scroll position are restored
+/// when the last one is released. Acquire one with [`ScrollLock::acquire`] and
+/// drop it to release it, or use the imperative [`ScrollLock::lock`] and
+/// [`ScrollLock::unlock`] pair.
+///
+/// On iOS, where `overflow: hidden` on the body does not stop touch scrolling,
+/// the body is pinned with `position: fixed` instead, and the previous scroll
+/// offset is restored on unlock.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use scroll_rs::{ScrollContainer, ScrollLock, ScrollLockOptions};
+///
+/// let lock = ScrollLock::acquire(&ScrollLockOptions {
+///     allow: vec![ScrollContainer::id("modal-body")],
+///     ..Default::default()
+/// });
+/// // ... the modal is open ...
+/// drop(lock);
+/// ```
+#[must_use = "the page is unlocked as soon as the lock is dropped"]
+#[derive(Debug)]
+pub struct ScrollLock {
+    id: u64,
+}
+
+thread_local! {
+    static LOCKS: RefCell<Locks> = RefCell::default();
+}
+
+#[derive(Default)]
+struct Locks {
+    next_id: u64,
+    allow: Vec<(u64, Vec<ScrollContainer>)>,
+    imperative: Vec<ScrollLock>,
+    applied: Option<Applied>,
+}
+
+/// What the first lock changed, so the last unlock can put it back.
+struct Applied {
+    window: Window,
+    body: HtmlElement,
+    styles: Vec<(&'static str, String)>,
+    scroll: Option<(f64, f64)>,
+    document: Document,
+    touch: Closure<dyn FnMut(Event)>,
+}
+
+impl ScrollLock {
+    /// Locks the page until the returned lock is dropped.
+    pub fn acquire(options: &ScrollLockOptions) -> Self {
+        LOCKS.with(|locks| {
+            let mut locks = locks.borrow_mut();
+            let id = locks.next_id;
+            locks.next_id += 1;
+            locks.allow.push((id, options.allow.clone()));
+            if locks.applied.is_none() {
+                locks.applied = apply(options.reserve_scrollbar);
+            }
+            ScrollLock { id }
+        })
+    }
+
+    /// Locks the page with the default options until [`ScrollLock::unlock`]
+    /// is called.
+    pub fn lock() {
+        Self::lock_with(&ScrollLockOptions::default());
+    }
+
+    /// Locks the page with `options` until [`ScrollLock::unlock`] is called.
+    pub fn lock_with(options: &ScrollLockOptions) {
+        let lock = Self::acquire(options);
+        LOCKS.with(|locks| locks.borrow_mut().imperative.push(lock));
+    }
+
+    /// Releases the most recent lock taken with [`ScrollLock::lock`].
+    ///
+    /// Does nothing when there is none.
+    pub fn unlock() {
+        let lock = LOCKS.with(|locks| locks.borrow_mut().imperative.pop());
+        drop(lock);
+    }
+
+    /// Whether the page is currently locked.
+    pub fn is_locked() -> bool {
+        LOCKS.with(|locks| !locks.borrow().allow.is_empty())
+    }
+}
+
+impl Drop for ScrollLock {
+    fn drop(&mut self) {
+        let applied = LOCKS.with(|locks| {
+            let mut locks = locks.borrow_mut();
+            locks.allow.retain(|(id, _)| *id != self.id);
+            if locks.allow.is_empty() {
+                locks.applied.take()
+            } else {
+                None
+            }
+        });
+        if let Some(applied) = applied {
+            restore(applied);
+        }
+    }
+}
+
+fn apply(reserve_scrollbar: bool) -> Option<Applied> {
+    let window = window()?;
+    let document = window.document()?;
+    let body = document.body()?;
+    let style = body.style();
+
+    let mut changes: Vec<(&'static str, String)> = vec![("overflow", "hidden".to_string())];
+    if reserve_scrollbar {
+        let viewport = window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or(0.0);
+        let content = document
+            .document_element()
+            .map_or(viewport, |root| f64::from(root.client_width()));
+        let scrollbar = viewport - content;
+        if scrollbar > 0.0 {
+            let padding = window
+                .get_computed_style(&body)
+                .ok()
+                .flatten()
+                .and_then(|computed| computed.get_property_value("padding-right").ok())
+                .and_then(|value| value.trim_end_matches("px").parse::<f64>().ok())
+                .unwrap_or(0.0);
+            changes.push(("padding-right", format!("{}px", padding + scrollbar)));
+        }
+    }
+
+    let scroll = is_ios(&window).then(|| {
+        let (top, left) = (
+            window.scroll_y().unwrap_or(0.0),
+            window.scroll_x().unwrap_or(0.0),
+        );
+        changes.push(("position", "fixed".to_string()));
+        changes.push(("top", format!("{}px", -top)));
+        changes.push(("left", format!("{}px", -left)));
+        changes.push(("width", "100%".to_string()));
+        (top, left)
+    });
+
+    let styles = changes
+        .into_iter()
+        .map(|(property, value)| {
+            let previous = style.get_property_value(property).unwrap_or_default();
+            let _ = style.set_property(property, &value);
+            (property, previous)
+        })
+        .collect();
+
+    // Touch scrolling can still reach the page through elements that are not
+    // scrollable themselves, so block it everywhere except the allow-list.
+    let touch = Closure::<dyn FnMut(Event)>::new(|event: Event| {
+        let target = event
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+        if !target.is_some_and(|target| is_allowed(&target)) {
+            event.prevent_default();
+        }
+    });
+    let options = AddEventListenerOptions::new();
+    options.set_passive(false);
+    let _ = document.add_event_listener_with_callback_and_add_event_listener_options(
+        "touchmove",
+        touch.as_ref().unchecked_ref(),
+        &options,
+    );
+
+    Some(Applied {
+        window,
+        body,
+        styles,
+        scroll,
+        document,
+        touch,
+    })
+}
+
+fn restore(applied: Applied) {
+    let style = applied.body.style();
+    for (property, previous) in &applied.styles {
+        if previous.is_empty() {
+            let _ = style.remove_property(property);
+        } else {
+            let _ = style.set_property(property, previous);
+        }
+    }
+    let _ = applied
+        .document
+        .remove_event_listener_with_callback("touchmove", applied.touch.as_ref().unchecked_ref());
+
+    if let Some((top, left)) = applied.scroll {
+        let options = ScrollToOptions::new();
+        options.set_top(top);
+        options.set_left(left);
+        options.set_behavior(ScrollBehavior::Instant);
+        applied.window.scroll_with_scroll_to_options(&options);
+    }
+}
+
+fn is_allowed(target: &Node) -> bool {
+    LOCKS.with(|locks| {
+        locks
+            .borrow()
+            .allow
+            .iter()
+            .flat_map(|(_, allow)| allow)
+            .filter_map(|container| container.resolve())
+            .any(|root| match root {
+                ScrollRoot::Element(element) => element.contains(Some(target)),
+                ScrollRoot::Window(_) => true,
+            })
+    })
+}
+
+/// iOS Safari ignores `overflow: hidden` on the body for touch scrolling. iPads
+/// report themselves as desktop Safari, but have touch points.
+fn is_ios(window: &Window) -> bool {
+    let navigator = window.navigator();
+    let user_agent = navigator.user_agent().unwrap_or_default();
+    ["iPhone", "iPad", "iPod"]
+        .iter()
+        .any(|device| user_agent.contains(device))
+        || (navigator.platform().unwrap_or_default() == "MacIntel"
+            && navigator.max_touch_points() > 1)
+}
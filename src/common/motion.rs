@@ -0,0 +1,95 @@
+use crate::common::container::listen;
+use crate::common::{window, Behavior, ScrollListener};
+use std::cell::{Cell, OnceCell};
+use std::rc::Rc;
+
+const REDUCED_MOTION_QUERY: &str = "(prefers-reduced-motion: reduce)";
+
+/// Whether scrolls honour the user's reduced-motion setting.
+///
+/// People with vestibular disorders can ask the operating system to reduce
+/// motion. By default smooth and animated scrolls then jump straight to their
+/// destination, as required by WCAG 2.3.3, without any call site checking the
+/// media query itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::{Behavior, ReducedMotion};
+///
+/// assert_eq!(ReducedMotion::Always.apply(&Behavior::Smooth), Behavior::Instant);
+/// assert_eq!(ReducedMotion::Never.apply(&Behavior::Smooth), Behavior::Smooth);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ReducedMotion {
+    /// Follow the `prefers-reduced-motion` media query.
+    #[default]
+    System,
+    /// Always scroll instantly.
+    Always,
+    /// Always keep the requested behavior, e.g. for a scroll the user asked to
+    /// watch.
+    Never,
+}
+
+impl ReducedMotion {
+    /// Whether motion should be reduced right now.
+    ///
+    /// [`ReducedMotion::System`] reads the media query, which is watched for
+    /// changes, so switching the OS setting takes effect on the next scroll.
+    /// Outside the browser it never reduces motion.
+    pub fn is_reduced(self) -> bool {
+        match self {
+            ReducedMotion::System => prefers_reduced_motion(),
+            ReducedMotion::Always => true,
+            ReducedMotion::Never => false,
+        }
+    }
+
+    /// Returns the behavior to scroll with instead of `behavior`.
+    ///
+    /// Smooth and animated scrolls become instant while motion is reduced.
+    /// `Auto` is left to the page's CSS `scroll-behavior`.
+    pub fn apply(self, behavior: &Behavior) -> Behavior {
+        match behavior {
+            Behavior::Smooth | Behavior::Animated { .. } if self.is_reduced() => Behavior::Instant,
+            behavior => behavior.clone(),
+        }
+    }
+}
+
+/// The reduced-motion media query, kept up to date by a `change` listener.
+struct MotionQuery {
+    reduced: Rc<Cell<bool>>,
+    _listener: ScrollListener,
+}
+
+thread_local! {
+    static QUERY: OnceCell<Option<MotionQuery>> = const { OnceCell::new() };
+}
+
+/// Whether the user asked the operating system to reduce motion.
+///
+/// The media query is looked up once and then updated from its `change`
+/// events, so this is cheap enough to call on every scroll.
+fn prefers_reduced_motion() -> bool {
+    QUERY.with(|query| {
+        query
+            .get_or_init(watch_query)
+            .as_ref()
+            .is_some_and(|query| query.reduced.get())
+    })
+}
+
+fn watch_query() -> Option<MotionQuery> {
+    let list = window()?.match_media(REDUCED_MOTION_QUERY).ok()??;
+    let reduced = Rc::new(Cell::new(list.matches()));
+    let listener = listen(&list, "change", {
+        let (reduced, list) = (reduced.clone(), list.clone());
+        move || reduced.set(list.matches())
+    });
+    Some(MotionQuery {
+        reduced,
+        _listener: listener,
+    })
+}
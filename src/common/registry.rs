@@ -0,0 +1,96 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+type Entries<T> = RefCell<Vec<(u64, T)>>;
+
+/// A list of subscribers that each remove themselves when dropped.
+///
+/// This is the bookkeeping behind [`ScrollRoot::on_scroll`](crate::common::ScrollRoot::on_scroll):
+/// every scroll root keeps one list, and its shared DOM listener is removed as
+/// soon as the list is empty. Because a subscriber is only ever held by its
+/// [`ScrollSubscription`], mounting and unmounting a component any number of
+/// times never leaves handlers behind.
+pub(crate) struct ScrollSubscribers<T> {
+    entries: Rc<Entries<T>>,
+    next_id: Cell<u64>,
+}
+
+impl<T> ScrollSubscribers<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self {
+            entries: Rc::new(RefCell::new(Vec::new())),
+            next_id: Cell::new(0),
+        }
+    }
+
+    /// Adds `value` to the list until the returned subscription is dropped.
+    pub fn subscribe(&self, value: T) -> ScrollSubscription<T> {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.entries.borrow_mut().push((id, value));
+        ScrollSubscription {
+            entries: Rc::downgrade(&self.entries),
+            id,
+        }
+    }
+
+    /// Number of live subscriptions.
+    #[cfg(all(test, target_arch = "wasm32"))]
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    /// Whether every subscription has been dropped.
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+}
+
+impl<T: Clone> ScrollSubscribers<T> {
+    /// Calls `f` with every subscriber.
+    ///
+    /// Subscribers may subscribe or unsubscribe, themselves or each other,
+    /// while `f` runs. Those dropped during the pass are skipped, and those
+    /// added during the pass run from the next one.
+    pub fn for_each(&self, mut f: impl FnMut(&T)) {
+        let snapshot = self.entries.borrow().clone();
+        for (id, value) in &snapshot {
+            if self.contains(*id) {
+                f(value);
+            }
+        }
+    }
+
+    fn contains(&self, id: u64) -> bool {
+        self.entries.borrow().iter().any(|(other, _)| *other == id)
+    }
+}
+
+impl<T> Default for ScrollSubscribers<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A place in a [`ScrollSubscribers`] list, given up when dropped.
+pub(crate) struct ScrollSubscription<T> {
+    entries: Weak<Entries<T>>,
+    id: u64,
+}
+
+impl<T> Drop for ScrollSubscription<T> {
+    fn drop(&mut self) {
+        let Some(entries) = self.entries.upgrade() else {
+            return;
+        };
+        // The value is dropped only after the borrow ends, as dropping it may
+        // drop other subscriptions of the same list.
+        let removed = {
+            let mut entries = entries.borrow_mut();
+            let index = entries.iter().position(|(other, _)| *other == self.id);
+            index.map(|index| entries.remove(index))
+        };
+        drop(removed);
+    }
+}
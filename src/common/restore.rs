@@ -0,0 +1,276 @@
+use crate::common::container::listen;
+use crate::common::frame::request_frames;
+use crate::common::hash::hash_id;
+use crate::common::{
+    window, Behavior, HashMode, ScrollContainer, ScrollEngine, ScrollListener, ScrollRequest,
+    ScrollTarget,
+};
+use js_sys::{Array, Object, Reflect};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{ScrollRestoration as HistoryScrollRestoration, Window};
+
+/// How many animation frames a restore waits for the page to grow tall enough
+/// to reach the saved position.
+const RESTORE_FRAMES: u32 = 30;
+
+/// Property of `history.state` holding the key of a history entry.
+const ENTRY_KEY: &str = "__scroll_rs_key";
+
+/// Configuration for scroll restoration across route changes.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScrollRestorationOptions {
+    /// Scrollable area whose position is saved and restored. Defaults to the
+    /// window.
+    pub container: ScrollContainer,
+
+    /// How restored positions, hash targets and the scroll to top are
+    /// performed. Defaults to [`Behavior::Instant`].
+    pub behavior: Behavior,
+
+    /// Offset in pixels added to the position of a URL hash target, e.g. the
+    /// negative height of a fixed header. Defaults to `0.0`.
+    pub offset: f64,
+}
+
+impl Default for ScrollRestorationOptions {
+    fn default() -> Self {
+        Self {
+            container: ScrollContainer::Window,
+            behavior: Behavior::Instant,
+            offset: 0.0,
+        }
+    }
+}
+
+/// Saves scroll positions per history entry and restores them on back/forward
+/// navigation.
+///
+/// Call [`ScrollRestoration::navigate`] with the new route key whenever the
+/// router changes routes. If the change was caused by the browser's back or
+/// forward buttons (a `popstate` event), the position last seen on that history
+/// entry is restored, so visiting the same route twice keeps two positions.
+/// Any other navigation scrolls to the element named by the URL hash, or to the
+/// top when there is none.
+///
+/// Entries are told apart by a key stored in `history.state` next to the
+/// router's own state. When a router replaces the state without it, the
+/// position last seen on the route is used instead.
+///
+/// While active, the browser's own restoration is switched to `manual` so it
+/// does not fight with the saved positions. It is switched back when dropped.
+pub struct ScrollRestoration {
+    state: Rc<RestorationState>,
+    _scroll: ScrollListener,
+    _popstate: ScrollListener,
+}
+
+struct RestorationState {
+    window: Window,
+    options: ScrollRestorationOptions,
+    positions: RefCell<HashMap<Entry, (f64, f64)>>,
+    route: RefCell<Option<String>>,
+    entry: RefCell<Option<String>>,
+    session: f64,
+    next_key: Cell<u32>,
+    url: RefCell<String>,
+    popped: Cell<bool>,
+}
+
+impl ScrollRestoration {
+    /// Starts recording scroll positions of the configured container.
+    ///
+    /// Returns `None` when there is no window or the container is not
+    /// mounted.
+    pub fn new(options: ScrollRestorationOptions) -> Option<Self> {
+        let window = window()?;
+        let root = options.container.resolve()?;
+        if let Ok(history) = window.history() {
+            let _ = history.set_scroll_restoration(HistoryScrollRestoration::Manual);
+        }
+
+        let state = Rc::new(RestorationState {
+            url: RefCell::new(current_url(&window)),
+            window: window.clone(),
+            options,
+            positions: RefCell::default(),
+            route: RefCell::default(),
+            entry: RefCell::default(),
+            session: js_sys::Date::now(),
+            next_key: Cell::new(0),
+            popped: Cell::new(false),
+        });
+
+        let scroll = {
+            let state = state.clone();
+            let listener_root = root.clone();
+            listener_root.on_scroll(move || {
+                // Scroll events fired after the URL changed but before the new
+                // route was reported belong to neither route.
+                if *state.url.borrow() != current_url(&state.window) {
+                    return;
+                }
+                let position = (root.scroll_top(), root.scroll_left());
+                let mut positions = state.positions.borrow_mut();
+                if let Some(route) = state.route.borrow().as_ref() {
+                    positions.insert(Entry::Route(route.clone()), position);
+                }
+                if let Some(key) = state.entry.borrow().as_ref() {
+                    positions.insert(Entry::Key(key.clone()), position);
+                }
+            })
+        };
+        let popstate = {
+            let state = state.clone();
+            listen(window.as_ref(), "popstate", move || state.popped.set(true))
+        };
+
+        Some(Self {
+            state,
+            _scroll: scroll,
+            _popstate: popstate,
+        })
+    }
+
+    /// Reports that the router now shows `route`.
+    ///
+    /// The first call only records the route. Reporting the current route
+    /// again does nothing.
+    pub fn navigate(&self, route: &str) {
+        let state = &self.state;
+        let previous = state.route.replace(Some(route.to_string()));
+        *state.url.borrow_mut() = current_url(&state.window);
+        let popped = state.popped.replace(false);
+        let key = entry_key(&state.window);
+        let entry = match &key {
+            Some(key) => Entry::Key(key.clone()),
+            None => Entry::Route(route.to_string()),
+        };
+        *state.entry.borrow_mut() = key.or_else(|| self.tag_entry());
+        match previous {
+            None => return,
+            Some(previous) if previous == route => return,
+            Some(_) => {}
+        }
+
+        let saved = state.positions.borrow().get(&entry).copied();
+        match saved {
+            Some((top, left)) if popped => self.restore(top, left),
+            _ => {
+                let target =
+                    hash_id(&state.window).map_or_else(ScrollTarget::default, ScrollTarget::Id);
+                self.scroll(target, state.options.offset);
+            }
+        }
+    }
+
+    /// Stores a fresh key in the current history entry's state and returns it.
+    ///
+    /// Returns `None`, leaving the entry alone, when the state set by the
+    /// router is not an object the key can be added to.
+    fn tag_entry(&self) -> Option<String> {
+        let state = &self.state;
+        let history = state.window.history().ok()?;
+        let data = history.state().ok()?;
+        let tagged = if data.is_null() || data.is_undefined() {
+            Object::new()
+        } else if data.is_object() && !Array::is_array(&data) {
+            Object::assign(&Object::new(), data.unchecked_ref())
+        } else {
+            return None;
+        };
+        let key = format!("{}-{}", state.session, state.next_key.get());
+        state.next_key.set(state.next_key.get() + 1);
+        Reflect::set(
+            &tagged,
+            &JsValue::from_str(ENTRY_KEY),
+            &JsValue::from_str(&key),
+        )
+        .ok()?;
+        history.replace_state(&tagged, "").ok()?;
+        Some(key)
+    }
+
+    /// Scrolls back to a saved position, waiting a few frames for content that
+    /// is still rendering to make it reachable.
+    fn restore(&self, top: f64, left: f64) {
+        let state = self.state.clone();
+        let mut frames = 0;
+        request_frames(move |_| {
+            let Ok(engine) = ScrollEngine::with_container(&state.options.container) else {
+                return false;
+            };
+            frames += 1;
+            if engine.root().max_scroll_top() < top && frames < RESTORE_FRAMES {
+                return true;
+            }
+            let _ = engine.scroll(&restore_request(
+                ScrollTarget::Position { top, left },
+                0.0,
+                &state.options,
+            ));
+            false
+        });
+    }
+
+    fn scroll(&self, target: ScrollTarget, offset: f64) {
+        if let Ok(engine) = ScrollEngine::with_container(&self.state.options.container) {
+            let _ = engine.scroll(&restore_request(target, offset, &self.state.options));
+        }
+    }
+}
+
+impl Drop for ScrollRestoration {
+    fn drop(&mut self) {
+        if let Ok(history) = self.state.window.history() {
+            let _ = history.set_scroll_restoration(HistoryScrollRestoration::Auto);
+        }
+    }
+}
+
+/// What a saved position belongs to.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+enum Entry {
+    /// A history entry, identified by the key stored in its state.
+    Key(String),
+    /// The last visited history entry showing a route.
+    Route(String),
+}
+
+/// The key stored in the current history entry's state, if any.
+fn entry_key(window: &Window) -> Option<String> {
+    let data = window.history().ok()?.state().ok()?;
+    if !data.is_object() {
+        return None;
+    }
+    Reflect::get(&data, &JsValue::from_str(ENTRY_KEY))
+        .ok()?
+        .as_string()
+}
+
+fn restore_request(
+    target: ScrollTarget,
+    offset: f64,
+    options: &ScrollRestorationOptions,
+) -> ScrollRequest {
+    ScrollRequest {
+        target,
+        offset,
+        behavior: options.behavior.clone(),
+        update_hash: HashMode::None,
+        ..Default::default()
+    }
+}
+
+/// The path and query of the current URL, which identify a page for the
+/// purpose of telling route changes apart.
+fn current_url(window: &Window) -> String {
+    let location = window.location();
+    format!(
+        "{}{}",
+        location.pathname().unwrap_or_default(),
+        location.search().unwrap_or_default()
+    )
+}
@@ -0,0 +1,113 @@
+use crate::common::{ScrollContainer, ScrollRoot};
+use js_sys::Array;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit,
+    MutationObserver, MutationObserverInit,
+};
+
+/// Default root margin of infinite-scroll sentinels: fire 200px before the
+/// sentinel actually becomes visible.
+pub const SENTINEL_ROOT_MARGIN: &str = "0px 0px 200px 0px";
+
+/// Watches a sentinel element and reports when it comes into view.
+///
+/// Infinite-scroll components place the sentinel after the last item of a list
+/// and load the next page once it is reached. An `IntersectionObserver` only
+/// reports the sentinel crossing into view, so after each report the sentinel
+/// is observed afresh as soon as the list around it changes: if the new page
+/// did not push it out of view, it is reported again, and loading continues
+/// until the list fills the container. The observer is disconnected when
+/// dropped.
+pub struct SentinelObserver {
+    observer: IntersectionObserver,
+    mutations: Option<MutationObserver>,
+    _callback: Closure<dyn FnMut(Array)>,
+    _on_mutation: Closure<dyn FnMut(Array)>,
+}
+
+impl SentinelObserver {
+    /// Starts observing `sentinel` within `container`.
+    ///
+    /// `on_enter` is called each time the sentinel enters the observed area,
+    /// which is the container's visible area grown by `root_margin`, and again
+    /// whenever the sentinel's parent changes afterwards while the sentinel is
+    /// still in that area. Returns `None` when the container is not mounted.
+    pub fn observe(
+        sentinel: &Element,
+        container: &ScrollContainer,
+        root_margin: &str,
+        mut on_enter: impl FnMut() + 'static,
+    ) -> Option<Self> {
+        let root = container.resolve()?;
+        let reached = Rc::new(Cell::new(false));
+
+        let callback = Closure::<dyn FnMut(Array)>::new({
+            let reached = reached.clone();
+            move |entries: Array| {
+                let entered = entries.iter().any(|entry| {
+                    entry
+                        .unchecked_into::<IntersectionObserverEntry>()
+                        .is_intersecting()
+                });
+                if entered {
+                    reached.set(true);
+                    on_enter();
+                }
+            }
+        });
+
+        let init = IntersectionObserverInit::new();
+        if let ScrollRoot::Element(element) = &root {
+            init.set_root(Some(element));
+        }
+        init.set_root_margin(root_margin);
+        init.set_threshold(&JsValue::from_f64(0.0));
+
+        let observer =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+                .ok()?;
+        observer.observe(sentinel);
+
+        // Observing the sentinel again makes the observer report where it is
+        // now, even though it never left the observed area.
+        let on_mutation = Closure::<dyn FnMut(Array)>::new({
+            let (observer, sentinel) = (observer.clone(), sentinel.clone());
+            move |_| {
+                if reached.replace(false) {
+                    observer.unobserve(&sentinel);
+                    observer.observe(&sentinel);
+                }
+            }
+        });
+        let mutations = sentinel.parent_node().and_then(|parent| {
+            let mutations = MutationObserver::new(on_mutation.as_ref().unchecked_ref()).ok()?;
+            let init = MutationObserverInit::new();
+            init.set_child_list(true);
+            init.set_subtree(true);
+            mutations
+                .observe_with_options(&parent, &init)
+                .ok()
+                .map(|()| mutations)
+        });
+
+        Some(Self {
+            observer,
+            mutations,
+            _callback: callback,
+            _on_mutation: on_mutation,
+        })
+    }
+}
+
+impl Drop for SentinelObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+        if let Some(mutations) = &self.mutations {
+            mutations.disconnect();
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::common::{window, ScrollContainer, ScrollRoot};
+use js_sys::Array;
+use std::collections::HashSet;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+/// Configuration for scroll-spy hooks and components.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScrollSpyOptions {
+    /// Offset in pixels added to the top of the observed area.
+    ///
+    /// Uses the same convention as the `offset` of `Scroll`: pass the negative
+    /// height of a fixed header (e.g. `-64.0`) so sections hidden behind it do
+    /// not count as visible.
+    pub offset: f64,
+
+    /// `IntersectionObserver` root margin, in CSS shorthand.
+    ///
+    /// Defaults to `"0px 0px -50% 0px"`, so a section becomes active once it
+    /// reaches the upper half of the viewport. When the top margin is given in
+    /// pixels, `offset` is added to it.
+    pub root_margin: String,
+
+    /// Fraction of a section (`0.0..=1.0`) that must be inside the observed
+    /// area before it counts as visible. Defaults to `0.0`.
+    pub threshold: f64,
+
+    /// Scrollable area the sections live in. Defaults to the window.
+    pub container: ScrollContainer,
+}
+
+impl Default for ScrollSpyOptions {
+    fn default() -> Self {
+        Self {
+            offset: 0.0,
+            root_margin: "0px 0px -50% 0px".to_string(),
+            threshold: 0.0,
+            container: ScrollContainer::Window,
+        }
+    }
+}
+
+impl ScrollSpyOptions {
+    /// The root margin handed to the `IntersectionObserver`, with `offset`
+    /// folded into the top margin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scroll_rs::ScrollSpyOptions;
+    ///
+    /// let options = ScrollSpyOptions {
+    ///     offset: -64.0,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(options.effective_root_margin(), "-64px 0px -50% 0px");
+    /// ```
+    pub fn effective_root_margin(&self) -> String {
+        let mut sides: Vec<&str> = self.root_margin.split_whitespace().collect();
+        if self.offset == 0.0 || sides.is_empty() {
+            return self.root_margin.clone();
+        }
+        // Expand the CSS shorthand so only the top side changes.
+        while sides.len() < 4 {
+            sides.push(match sides.len() {
+                1 | 2 => sides[0],
+                _ => sides[1],
+            });
+        }
+        let Some(top) = sides[0]
+            .strip_suffix("px")
+            .and_then(|px| px.parse::<f64>().ok())
+        else {
+            return self.root_margin.clone();
+        };
+        format!(
+            "{}px {} {} {}",
+            top + self.offset,
+            sides[1],
+            sides[2],
+            sides[3]
+        )
+    }
+}
+
+/// Watches a list of sections and reports which one is currently active.
+///
+/// The active section is the first one, in the order the IDs were given, that
+/// intersects the observed area. When no section intersects, the previously
+/// active one is kept. The observer is disconnected when dropped.
+pub struct ScrollSpyObserver {
+    observer: IntersectionObserver,
+    _callback: Closure<dyn FnMut(Array)>,
+}
+
+impl ScrollSpyObserver {
+    /// Starts observing the elements with the given `ids`.
+    ///
+    /// `on_change` is called with the new active ID whenever it changes. IDs
+    /// that do not match an element are ignored. Returns `None` when there is
+    /// no window or the container is not mounted.
+    pub fn observe(
+        ids: &[String],
+        options: &ScrollSpyOptions,
+        mut on_change: impl FnMut(Option<String>) + 'static,
+    ) -> Option<Self> {
+        let document = window()?.document()?;
+        let root = options.container.resolve()?;
+
+        let order = ids.to_vec();
+        let mut visible = HashSet::new();
+        let mut active: Option<String> = None;
+        let callback = Closure::<dyn FnMut(Array)>::new(move |entries: Array| {
+            for entry in entries.iter() {
+                let entry: IntersectionObserverEntry = entry.unchecked_into();
+                let id = entry.target().id();
+                if entry.is_intersecting() {
+                    visible.insert(id);
+                } else {
+                    visible.remove(&id);
+                }
+            }
+            let next = order
+                .iter()
+                .find(|id| visible.contains(*id))
+                .cloned()
+                .or_else(|| active.clone());
+            if next != active {
+                active = next.clone();
+                on_change(next);
+            }
+        });
+
+        let init = IntersectionObserverInit::new();
+        if let ScrollRoot::Element(element) = &root {
+            init.set_root(Some(element));
+        }
+        init.set_root_margin(&options.effective_root_margin());
+        init.set_threshold(&JsValue::from_f64(options.threshold));
+
+        let observer =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+                .ok()?;
+        for id in ids {
+            if let Some(element) = document.get_element_by_id(id) {
+                observer.observe(&element);
+            }
+        }
+
+        Some(Self {
+            observer,
+            _callback: callback,
+        })
+    }
+}
+
+impl Drop for ScrollSpyObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
@@ -0,0 +1,304 @@
+use crate::common::{window, ScrollContainer, ScrollListener};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::Window;
+
+/// Which way a scroll root is moving.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ScrollDirection {
+    /// Scrolling towards the top.
+    Up,
+    /// Scrolling towards the bottom.
+    Down,
+    /// Scrolling towards the left edge.
+    Left,
+    /// Scrolling towards the right edge.
+    Right,
+    /// Not scrolling, or not moved further than the tolerance yet.
+    #[default]
+    Idle,
+}
+
+/// A snapshot of a scroll root's position and movement.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ScrollState {
+    /// Vertical scroll position in pixels.
+    pub top: f64,
+    /// Horizontal scroll position in pixels.
+    pub left: f64,
+    /// Current direction of movement.
+    pub direction: ScrollDirection,
+    /// Scroll speed in pixels per second. Zero once scrolling has settled.
+    pub velocity: f64,
+    /// Whether the root is scrolled all the way up.
+    pub at_top: bool,
+    /// Whether the root is scrolled all the way down.
+    pub at_bottom: bool,
+}
+
+impl Default for ScrollState {
+    fn default() -> Self {
+        Self {
+            top: 0.0,
+            left: 0.0,
+            direction: ScrollDirection::Idle,
+            velocity: 0.0,
+            at_top: true,
+            at_bottom: false,
+        }
+    }
+}
+
+/// Configuration for scroll-state hooks.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScrollStateOptions {
+    /// Distance in pixels the root must move before the direction changes.
+    ///
+    /// Filters out jitter from trackpads and rubber-band scrolling. Defaults
+    /// to `5.0`.
+    pub tolerance: f64,
+
+    /// Milliseconds without a `scroll` event after which the direction goes
+    /// back to [`ScrollDirection::Idle`] and the velocity to zero. Defaults to
+    /// `150`.
+    pub idle_ms: u32,
+
+    /// Scrollable area to watch. Defaults to the window.
+    pub container: ScrollContainer,
+}
+
+impl Default for ScrollStateOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 5.0,
+            idle_ms: 150,
+            container: ScrollContainer::Window,
+        }
+    }
+}
+
+/// Turns a stream of scroll positions into [`ScrollState`]s.
+///
+/// This is the framework- and DOM-independent part of the scroll-state hooks.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::common::{ScrollDirection, ScrollTracker};
+///
+/// let mut tracker = ScrollTracker::new(5.0);
+/// let max_top = 1000.0;
+///
+/// // Small movements stay below the tolerance.
+/// assert_eq!(tracker.update(3.0, 0.0, max_top, 0.0).direction, ScrollDirection::Idle);
+///
+/// let state = tracker.update(103.0, 0.0, max_top, 100.0);
+/// assert_eq!(state.direction, ScrollDirection::Down);
+/// assert_eq!(state.velocity, 1000.0);
+///
+/// // Jitter does not flip the direction...
+/// assert_eq!(tracker.update(101.0, 0.0, max_top, 110.0).direction, ScrollDirection::Down);
+/// // ...but a real movement does.
+/// assert_eq!(tracker.update(50.0, 0.0, max_top, 160.0).direction, ScrollDirection::Up);
+///
+/// let state = tracker.settle();
+/// assert_eq!(state.direction, ScrollDirection::Idle);
+/// assert_eq!(state.velocity, 0.0);
+/// assert!(tracker.update(1000.0, 0.0, max_top, 300.0).at_bottom);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScrollTracker {
+    tolerance: f64,
+    anchor: (f64, f64),
+    time: Option<f64>,
+    state: ScrollState,
+}
+
+impl ScrollTracker {
+    /// Creates a tracker that ignores movements shorter than `tolerance`
+    /// pixels.
+    pub fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance: tolerance.max(0.0),
+            anchor: (0.0, 0.0),
+            time: None,
+            state: ScrollState::default(),
+        }
+    }
+
+    /// The most recent state.
+    pub fn state(&self) -> ScrollState {
+        self.state
+    }
+
+    /// Records a new position, observed at `now` milliseconds, within a root
+    /// that can scroll down to `max_top`.
+    pub fn update(&mut self, top: f64, left: f64, max_top: f64, now: f64) -> ScrollState {
+        let previous = self.state;
+        let velocity = match self.time {
+            Some(time) if now > time => {
+                let distance = (top - previous.top).hypot(left - previous.left);
+                distance / (now - time) * 1000.0
+            }
+            _ => previous.velocity,
+        };
+
+        let (dx, dy) = (left - self.anchor.0, top - self.anchor.1);
+        let direction = if dy.abs() >= dx.abs() && dy.abs() >= self.tolerance && dy != 0.0 {
+            if dy > 0.0 {
+                ScrollDirection::Down
+            } else {
+                ScrollDirection::Up
+            }
+        } else if dx.abs() >= self.tolerance && dx != 0.0 {
+            if dx > 0.0 {
+                ScrollDirection::Right
+            } else {
+                ScrollDirection::Left
+            }
+        } else {
+            previous.direction
+        };
+        if direction != previous.direction || dx.abs().max(dy.abs()) >= self.tolerance {
+            self.anchor = (left, top);
+        }
+
+        self.time = Some(now);
+        self.state = ScrollState {
+            top,
+            left,
+            direction,
+            velocity,
+            at_top: top <= 0.0,
+            at_bottom: top >= max_top - 1.0,
+        };
+        self.state
+    }
+
+    /// Marks scrolling as settled: the direction becomes
+    /// [`ScrollDirection::Idle`] and the velocity zero.
+    pub fn settle(&mut self) -> ScrollState {
+        self.time = None;
+        self.state.direction = ScrollDirection::Idle;
+        self.state.velocity = 0.0;
+        self.state
+    }
+}
+
+/// Watches a scroll root and reports its [`ScrollState`].
+///
+/// `on_change` is only called when the state actually changes. Listeners and
+/// the idle timer are removed when dropped.
+pub struct ScrollStateObserver {
+    _listener: ScrollListener,
+    idle: Rc<IdleTimer>,
+}
+
+struct IdleTimer {
+    window: Window,
+    id: Cell<Option<i32>>,
+    closure: RefCell<Option<Closure<dyn FnMut()>>>,
+}
+
+impl IdleTimer {
+    fn restart(&self, delay: u32) {
+        self.cancel();
+        if let Some(closure) = self.closure.borrow().as_ref() {
+            self.id.set(
+                self.window
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(
+                        closure.as_ref().unchecked_ref(),
+                        i32::try_from(delay).unwrap_or(i32::MAX),
+                    )
+                    .ok(),
+            );
+        }
+    }
+
+    fn cancel(&self) {
+        if let Some(id) = self.id.take() {
+            self.window.clear_timeout_with_handle(id);
+        }
+    }
+}
+
+impl ScrollStateObserver {
+    /// Starts watching the container named in `options`.
+    ///
+    /// `on_change` is called once right away with the current state. Returns
+    /// `None` when there is no window or the container is not mounted.
+    pub fn observe(
+        options: &ScrollStateOptions,
+        on_change: impl FnMut(ScrollState) + 'static,
+    ) -> Option<Self> {
+        let window = window()?;
+        let root = options.container.resolve()?;
+        let on_change = Rc::new(RefCell::new(on_change));
+        let tracker = Rc::new(RefCell::new(ScrollTracker::new(options.tolerance)));
+
+        let initial = tracker.borrow_mut().update(
+            root.scroll_top(),
+            root.scroll_left(),
+            root.max_scroll_top(),
+            js_sys::Date::now(),
+        );
+        tracker.borrow_mut().settle();
+        (on_change.borrow_mut())(initial);
+
+        let idle = Rc::new(IdleTimer {
+            window,
+            id: Cell::new(None),
+            closure: RefCell::new(None),
+        });
+        *idle.closure.borrow_mut() = Some(Closure::new({
+            let tracker = tracker.clone();
+            let on_change = on_change.clone();
+            let idle = Rc::downgrade(&idle);
+            move || {
+                if let Some(idle) = idle.upgrade() {
+                    idle.id.set(None);
+                }
+                let previous = tracker.borrow().state();
+                let state = tracker.borrow_mut().settle();
+                if state != previous {
+                    (on_change.borrow_mut())(state);
+                }
+            }
+        }));
+
+        let idle_ms = options.idle_ms;
+        let listener = root.on_scroll({
+            let root = root.clone();
+            let idle = idle.clone();
+            move || {
+                let previous = tracker.borrow().state();
+                let state = tracker.borrow_mut().update(
+                    root.scroll_top(),
+                    root.scroll_left(),
+                    root.max_scroll_top(),
+                    js_sys::Date::now(),
+                );
+                if state != previous {
+                    (on_change.borrow_mut())(state);
+                }
+                if idle_ms > 0 {
+                    idle.restart(idle_ms);
+                }
+            }
+        });
+
+        Some(Self {
+            _listener: listener,
+            idle,
+        })
+    }
+}
+
+impl Drop for ScrollStateObserver {
+    fn drop(&mut self) {
+        self.idle.cancel();
+        self.idle.closure.borrow_mut().take();
+    }
+}
@@ -0,0 +1,170 @@
+use crate::common::{window, ScrollError};
+use std::fmt;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, FocusOptions, HtmlElement};
+
+/// Where a scroll should end up.
+///
+/// Every `Scroll` component and [`ScrollRequest`](crate::ScrollRequest) takes a
+/// `ScrollTarget`, which makes the intent of a scroll explicit instead of
+/// inferring it from a combination of coordinates and element IDs.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::ScrollTarget;
+///
+/// // Back to the top of the page (the default).
+/// assert_eq!(ScrollTarget::default(), ScrollTarget::Position { top: 0.0, left: 0.0 });
+///
+/// // An element by ID or by CSS selector.
+/// let install = ScrollTarget::id("install");
+/// let first_heading = ScrollTarget::selector("article h2");
+///
+/// // Half way down the page, or one screen further.
+/// let middle = ScrollTarget::Percent(50.0);
+/// let next = ScrollTarget::By { dx: 0.0, dy: 800.0 };
+/// # let _ = (install, first_heading, middle, next);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum ScrollTarget {
+    /// An absolute position in pixels.
+    Position { top: f64, left: f64 },
+    /// The element with the given `id` attribute.
+    ///
+    /// An empty ID targets no element and scrolls to the top, like the
+    /// default target.
+    Id(String),
+    /// The first element matching a CSS selector.
+    Selector(String),
+    /// An element obtained from a framework node reference.
+    Node(NodeHandle),
+    /// The very end of the scrollable area.
+    Bottom,
+    /// A percentage (`0.0..=100.0`) of the scrollable height.
+    Percent(f64),
+    /// A delta relative to the current scroll position.
+    By { dx: f64, dy: f64 },
+}
+
+impl Default for ScrollTarget {
+    fn default() -> Self {
+        ScrollTarget::Position {
+            top: 0.0,
+            left: 0.0,
+        }
+    }
+}
+
+impl ScrollTarget {
+    /// Targets an absolute position in pixels.
+    pub fn position(top: f64, left: f64) -> Self {
+        ScrollTarget::Position { top, left }
+    }
+
+    /// Targets the element with the given `id` attribute.
+    pub fn id(id: impl Into<String>) -> Self {
+        ScrollTarget::Id(id.into())
+    }
+
+    /// Targets the first element matching a CSS selector.
+    pub fn selector(selector: impl Into<String>) -> Self {
+        ScrollTarget::Selector(selector.into())
+    }
+
+    /// Targets an element resolved lazily when the scroll happens.
+    ///
+    /// Framework adapters use this to turn their node references into targets.
+    pub fn node(resolve: impl Fn() -> Option<Element> + 'static) -> Self {
+        ScrollTarget::Node(NodeHandle::new(resolve))
+    }
+
+    /// Looks up the element behind an element-based target.
+    ///
+    /// Returns `None` for position-based targets and for elements that are not
+    /// currently in the document.
+    pub fn element(&self) -> Option<Element> {
+        self.try_element().ok().flatten()
+    }
+
+    /// Looks up the element behind an element-based target, reporting why it
+    /// could not be found.
+    ///
+    /// Returns `Ok(None)` for position-based targets.
+    pub fn try_element(&self) -> Result<Option<Element>, ScrollError> {
+        let document = || {
+            window()
+                .ok_or(ScrollError::NoWindow)?
+                .document()
+                .ok_or(ScrollError::NoDocument)
+        };
+        let (element, label) = match self {
+            ScrollTarget::Id(id) if !id.is_empty() => {
+                (document()?.get_element_by_id(id), format!("#{id}"))
+            }
+            ScrollTarget::Selector(selector) => (
+                document()?
+                    .query_selector(selector)
+                    .map_err(|_| ScrollError::InvalidSelector(selector.clone()))?,
+                selector.clone(),
+            ),
+            ScrollTarget::Node(node) => (node.get(), "node".to_string()),
+            _ => return Ok(None),
+        };
+        element.map(Some).ok_or(ScrollError::TargetNotFound(label))
+    }
+
+    /// Moves keyboard focus to the element behind an element-based target,
+    /// without scrolling to it again.
+    ///
+    /// Elements that cannot receive focus on their own, such as sections and
+    /// headings, get `tabindex="-1"` first. Returns `false` for position-based
+    /// targets and for elements that are not currently in the document.
+    pub fn focus(&self) -> bool {
+        let Some(element) = self
+            .element()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+        else {
+            return false;
+        };
+        if element.tab_index() < 0 && !element.has_attribute("tabindex") {
+            let _ = element.set_attribute("tabindex", "-1");
+        }
+        let options = FocusOptions::new();
+        options.set_prevent_scroll(true);
+        element.focus_with_options(&options).is_ok()
+    }
+}
+
+/// A lazily resolved DOM element.
+///
+/// Node references are only populated after the component has rendered, so the
+/// element is looked up at the moment it is needed rather than when the target
+/// is built. Two handles are equal when they share the same resolver.
+#[derive(Clone)]
+pub struct NodeHandle(Rc<dyn Fn() -> Option<Element>>);
+
+impl NodeHandle {
+    /// Wraps a closure that looks up the element on demand.
+    pub fn new(resolve: impl Fn() -> Option<Element> + 'static) -> Self {
+        NodeHandle(Rc::new(resolve))
+    }
+
+    /// Returns the referenced element, if it is currently mounted.
+    pub fn get(&self) -> Option<Element> {
+        (self.0)()
+    }
+}
+
+impl PartialEq for NodeHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for NodeHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NodeHandle(..)")
+    }
+}
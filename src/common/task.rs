@@ -0,0 +1,337 @@
+use crate::common::container::{listen_event, listen_passive};
+use crate::common::frame::FrameHandle;
+use crate::common::{window, ScrollError, ScrollListener, ScrollRoot};
+use js_sys::{Function, Promise};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Element, Event, HtmlElement, KeyboardEvent};
+
+/// Keys that scroll the page, and therefore take over from a running scroll.
+///
+/// Only while focus is not on a control that handles them itself, see
+/// [`is_scroll_key`].
+const SCROLL_KEYS: [&str; 9] = [
+    "ArrowUp",
+    "ArrowDown",
+    "ArrowLeft",
+    "ArrowRight",
+    "PageUp",
+    "PageDown",
+    "Home",
+    "End",
+    " ",
+];
+
+type Callback = Box<dyn FnOnce()>;
+
+/// How a scroll ended.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ScrollOutcome {
+    /// Vertical scroll position of the root once the scroll ended.
+    pub top: f64,
+
+    /// Horizontal scroll position of the root once the scroll ended.
+    pub left: f64,
+
+    /// Whether the root ended up within a pixel of the destination, clamped to
+    /// the reachable range.
+    pub reached: bool,
+
+    /// Whether the scroll was cancelled, e.g. by the user taking over.
+    pub cancelled: bool,
+}
+
+/// A scroll that is waiting for its delay, moving, or settling.
+///
+/// A task ends in exactly one of two ways: it finishes and calls its `on_end`
+/// callback, or it is cancelled and calls its `on_cancel` callback. Wheel,
+/// touch-drag and scrolling-key input from the user cancels it, so scrolls
+/// never fight the user. A task whose scroll cannot be started, e.g. because its
+/// target is missing, fails without calling either. [`ScrollTask::finished`]
+/// resolves once the task has ended, whichever way. Cloning a task yields another handle to the same scroll;
+/// dropping the handles does not stop it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use scroll_rs::{ScrollEngine, ScrollRequest, ScrollTarget, ScrollTask};
+///
+/// let task = ScrollTask::new(|| log("arrived"), || log("cancelled"));
+/// task.start_after(300, |task| {
+///     let request = ScrollRequest { target: ScrollTarget::Bottom, ..Default::default() };
+///     if let Ok(engine) = ScrollEngine::new() {
+///         let _ = engine.run(&request, task);
+///     }
+/// });
+/// // Changed our mind.
+/// task.cancel();
+/// # fn log(_: &str) {}
+/// ```
+#[derive(Clone)]
+pub struct ScrollTask(Rc<TaskState>);
+
+struct TaskState {
+    on_end: RefCell<Option<Callback>>,
+    on_cancel: RefCell<Option<Callback>>,
+    timer: Cell<Option<i32>>,
+    timer_closure: RefCell<Option<Closure<dyn FnMut()>>>,
+    frames: RefCell<Option<FrameHandle>>,
+    listeners: RefCell<Vec<ScrollListener>>,
+    root: RefCell<Option<ScrollRoot>>,
+    destination: Cell<Option<(f64, f64)>>,
+    end: RefCell<Option<Result<ScrollOutcome, ScrollError>>>,
+    waiters: RefCell<Vec<Function>>,
+}
+
+impl ScrollTask {
+    /// Creates a running task that calls `on_end` when it finishes and
+    /// `on_cancel` when it is cancelled.
+    pub fn new(on_end: impl FnOnce() + 'static, on_cancel: impl FnOnce() + 'static) -> Self {
+        let task = ScrollTask(Rc::new(TaskState {
+            on_end: RefCell::new(Some(Box::new(on_end))),
+            on_cancel: RefCell::new(Some(Box::new(on_cancel))),
+            timer: Cell::new(None),
+            timer_closure: RefCell::new(None),
+            frames: RefCell::new(None),
+            listeners: RefCell::new(Vec::new()),
+            root: RefCell::new(None),
+            destination: Cell::new(None),
+            end: RefCell::new(None),
+            waiters: RefCell::new(Vec::new()),
+        }));
+
+        if let Some(window) = window() {
+            // Dragging cancels, but a tap does not: its `touchstart` would
+            // otherwise end the task before the `click` that should stop it.
+            for event in ["wheel", "touchmove"] {
+                let this = task.clone();
+                task.add_listener(listen_passive(window.as_ref(), event, move || {
+                    this.cancel();
+                }));
+            }
+            let this = task.clone();
+            task.add_listener(listen_event(
+                window.as_ref(),
+                "keydown",
+                move |event: Event| {
+                    if event.dyn_ref::<KeyboardEvent>().is_some_and(is_scroll_key) {
+                        this.cancel();
+                    }
+                },
+            ));
+        }
+        task
+    }
+
+    /// Calls `start` after `delay_ms` milliseconds, or right away when the
+    /// delay is zero. Cancelling the task before then clears the timer.
+    pub fn start_after(&self, delay_ms: u32, start: impl FnOnce(&ScrollTask) + 'static) {
+        let Some(window) = window().filter(|_| delay_ms > 0) else {
+            return start(self);
+        };
+        let this = self.clone();
+        let mut start = Some(start);
+        let closure = Closure::<dyn FnMut()>::new(move || {
+            this.0.timer.set(None);
+            this.0.timer_closure.borrow_mut().take();
+            if let Some(start) = start.take() {
+                if this.is_running() {
+                    start(&this);
+                }
+            }
+        });
+        self.0.timer.set(
+            window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    closure.as_ref().unchecked_ref(),
+                    i32::try_from(delay_ms).unwrap_or(i32::MAX),
+                )
+                .ok(),
+        );
+        *self.0.timer_closure.borrow_mut() = Some(closure);
+    }
+
+    /// Whether the task has neither finished nor been cancelled.
+    pub fn is_running(&self) -> bool {
+        self.0.on_end.borrow().is_some()
+    }
+
+    /// Stops the scroll where it is and calls `on_cancel`.
+    ///
+    /// Returns `false`, and does nothing, when the task is already over.
+    pub fn cancel(&self) -> bool {
+        if !self.is_running() {
+            return false;
+        }
+        let root = self.0.root.borrow_mut().take();
+        self.release();
+        // Issuing a new scroll to the current position halts a native smooth
+        // scroll in flight.
+        if let Some(root) = &root {
+            root.scroll_to(root.scroll_top(), root.scroll_left());
+        }
+        self.settle(Ok(self.outcome(root.as_ref(), true)));
+        let on_cancel = self.0.on_cancel.borrow_mut().take();
+        if let Some(on_cancel) = on_cancel {
+            on_cancel();
+        }
+        true
+    }
+
+    /// Waits until the task has ended and reports how.
+    ///
+    /// Resolves right away when the task is already over. Fails with the
+    /// reason the scroll could not be started, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use scroll_rs::{ScrollController, ScrollOptions, ScrollTarget};
+    ///
+    /// async fn reveal(controller: &ScrollController) {
+    ///     let task = controller.scroll_to(ScrollTarget::id("details"));
+    ///     if let Ok(outcome) = task.finished().await {
+    ///         if outcome.reached {
+    ///             open_popover();
+    ///         }
+    ///     }
+    /// }
+    /// # fn open_popover() {}
+    /// ```
+    pub async fn finished(&self) -> Result<ScrollOutcome, ScrollError> {
+        loop {
+            if let Some(end) = self.0.end.borrow().clone() {
+                return end;
+            }
+            let promise = Promise::new(&mut |resolve, _| {
+                self.0.waiters.borrow_mut().push(resolve);
+            });
+            let _ = JsFuture::from(promise).await;
+        }
+    }
+
+    /// Marks the task as finished and calls `on_end`.
+    pub(crate) fn finish(&self) {
+        let on_end = self.0.on_end.borrow_mut().take();
+        let root = self.0.root.borrow().clone();
+        self.release();
+        if on_end.is_some() {
+            self.settle(Ok(self.outcome(root.as_ref(), false)));
+        }
+        if let Some(on_end) = on_end {
+            on_end();
+        }
+    }
+
+    /// Ends the task with `error` without calling back, e.g. when its scroll
+    /// could not be started.
+    pub(crate) fn fail(&self, error: ScrollError) {
+        let running = self.is_running();
+        self.0.on_cancel.borrow_mut().take();
+        self.release();
+        if running {
+            self.settle(Err(error));
+        }
+    }
+
+    /// Records the root being scrolled, so a cancel can halt it.
+    pub(crate) fn set_root(&self, root: ScrollRoot) {
+        *self.0.root.borrow_mut() = Some(root);
+    }
+
+    /// Records where the scroll is headed, to report whether it got there.
+    pub(crate) fn set_destination(&self, top: f64, left: f64) {
+        self.0.destination.set(Some((top, left)));
+    }
+
+    /// Ties a frame loop to the task; it is stopped when the task ends.
+    pub(crate) fn set_frames(&self, frames: FrameHandle) {
+        if self.is_running() {
+            *self.0.frames.borrow_mut() = Some(frames);
+        } else {
+            frames.cancel();
+        }
+    }
+
+    /// Ties an event listener to the task; it is removed when the task ends.
+    pub(crate) fn add_listener(&self, listener: ScrollListener) {
+        if self.is_running() {
+            self.0.listeners.borrow_mut().push(listener);
+        }
+    }
+
+    /// Measures where `root` ended up relative to the destination.
+    fn outcome(&self, root: Option<&ScrollRoot>, cancelled: bool) -> ScrollOutcome {
+        let Some(root) = root else {
+            return ScrollOutcome {
+                cancelled,
+                ..Default::default()
+            };
+        };
+        let (top, left) = (root.scroll_top(), root.scroll_left());
+        let reached = self.0.destination.get().is_some_and(|(to_top, to_left)| {
+            (top - to_top.clamp(0.0, root.max_scroll_top())).abs() < 1.0
+                && (left - to_left.clamp(0.0, root.max_scroll_left())).abs() < 1.0
+        });
+        ScrollOutcome {
+            top,
+            left,
+            reached,
+            cancelled,
+        }
+    }
+
+    /// Records how the task ended and wakes everyone waiting on it.
+    fn settle(&self, end: Result<ScrollOutcome, ScrollError>) {
+        *self.0.end.borrow_mut() = Some(end);
+        let waiters = std::mem::take(&mut *self.0.waiters.borrow_mut());
+        for resolve in waiters {
+            let _ = resolve.call0(&JsValue::NULL);
+        }
+    }
+
+    /// Drops everything that keeps the task alive, without calling back.
+    fn release(&self) {
+        self.0.on_end.borrow_mut().take();
+        if let (Some(id), Some(window)) = (self.0.timer.take(), window()) {
+            window.clear_timeout_with_handle(id);
+        }
+        let timer = self.0.timer_closure.borrow_mut().take();
+        let frames = self.0.frames.borrow_mut().take();
+        if let Some(frames) = &frames {
+            frames.cancel();
+        }
+        let listeners = std::mem::take(&mut *self.0.listeners.borrow_mut());
+        self.0.root.borrow_mut().take();
+        // Dropped last and outside of any borrow, as one of them may be the
+        // closure currently running.
+        drop((timer, frames, listeners));
+    }
+}
+
+/// Whether `event` scrolls the page.
+///
+/// Keys pressed in buttons, form fields and editable content are handled by
+/// that element, e.g. Space activates a button or types a space, and so are
+/// keys whose default action was prevented.
+fn is_scroll_key(event: &KeyboardEvent) -> bool {
+    if event.default_prevented() || !SCROLL_KEYS.contains(&event.key().as_str()) {
+        return false;
+    }
+    let Some(target) = event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+    else {
+        return true;
+    };
+    let handles_keys = matches!(
+        target.tag_name().to_ascii_lowercase().as_str(),
+        "button" | "input" | "textarea" | "select"
+    ) || target
+        .dyn_ref::<HtmlElement>()
+        .is_some_and(HtmlElement::is_content_editable);
+    !handles_keys
+}
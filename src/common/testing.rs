@@ -0,0 +1,36 @@
+//! Helpers for the browser tests of the framework adapters.
+
+pub(crate) use crate::common::container::live_listeners;
+pub(crate) use crate::common::hub::live_subscribers;
+use js_sys::Promise;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlElement;
+
+/// A new, empty element appended to the document body to mount a component
+/// into.
+pub(crate) fn mount_point() -> HtmlElement {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let element = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&element).unwrap();
+    element.unchecked_into()
+}
+
+/// Waits a couple of event loop turns, so effects and cleanups scheduled by a
+/// mount or unmount have run.
+pub(crate) async fn settle() {
+    for _ in 0..2 {
+        let tick = Promise::new(&mut |resolve, _| {
+            let _ = web_sys::window()
+                .unwrap()
+                .set_timeout_with_callback(&resolve);
+        });
+        let _ = JsFuture::from(tick).await;
+    }
+}
+
+/// Asserts that nothing mounted before is still listening to the DOM.
+pub(crate) fn assert_released() {
+    assert_eq!(live_subscribers(), 0, "scroll subscribers left behind");
+    assert_eq!(live_listeners(), 0, "DOM listeners left behind");
+}
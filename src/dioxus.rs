@@ -1,9 +1,31 @@
-use crate::common::{Behavior, SCROLL_TO_TOP_STYLE};
+mod controller;
+mod hash;
+mod headless;
+mod infinite;
+mod lock;
+mod progress;
+mod restore;
+mod spy;
+mod state;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests;
+
+pub use controller::use_scroll;
+pub use hash::use_scroll_to_hash;
+pub use headless::{HeadlessScroll, HeadlessScrollProps, ScrollTrigger};
+pub use infinite::{InfiniteScroll, InfiniteScrollProps};
+pub use lock::use_scroll_lock;
+pub use progress::{use_scroll_progress, ScrollProgress, ScrollProgressProps};
+pub use restore::use_scroll_restoration;
+pub use spy::{use_scroll_spy, ScrollSpy, ScrollSpyProps};
+pub use state::{use_scroll_direction, use_scroll_state};
+
+use crate::common::{
+    Behavior, HashMode, NodeHandle, ReducedMotion, ScrollContainer, ScrollError, ScrollTarget,
+    SCROLL_TO_TOP_STYLE,
+};
 use dioxus::prelude::*;
-use wasm_bindgen::closure::Closure;
-use wasm_bindgen::JsCast;
-use wasm_bindgen::JsValue;
-use web_sys::{window, ScrollBehavior, ScrollToOptions};
+use std::rc::Rc;
 
 /// Properties for configuring the `Scroll` component.
 ///
@@ -14,17 +36,17 @@ use web_sys::{window, ScrollBehavior, ScrollToOptions};
 pub struct ScrollProps {
     /// Custom inline styles for the scroll-to-top button.
     ///
-    /// Accepts a `&'static str` to define CSS properties for the button.
+    /// Accepts any string, so styles can be computed at runtime.
     /// Defaults to the built-in `SCROLL_TO_TOP_STYLE`.
-    #[props(default = SCROLL_TO_TOP_STYLE)]
-    pub style: &'static str,
+    #[props(default = SCROLL_TO_TOP_STYLE.to_string(), into)]
+    pub style: String,
 
     /// Custom CSS classes for the scroll-to-top button.
     ///
-    /// Accepts a `&'static str` for additional styling using class selectors.
+    /// Accepts any string for additional styling using class selectors.
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(default, into)]
+    pub class: String,
 
     /// Custom icon for the scroll button.
     ///
@@ -40,18 +62,21 @@ pub struct ScrollProps {
     #[props(default = Behavior::Smooth)]
     pub behavior: Behavior,
 
-    /// Vertical scroll target position in pixels.
+    /// Target of the scroll action.
     ///
-    /// Specifies the top position to scroll to. Defaults to `0.0`.
-    #[props(default = 0.0)]
-    pub top: f64,
+    /// Can be an absolute position, an element ID, a CSS selector, a mounted
+    /// node, the page bottom, a percentage of the page or a relative delta.
+    /// Defaults to the top of the page.
+    #[props(default = ScrollTarget::default())]
+    pub target: ScrollTarget,
 
-    /// Horizontal scroll target position in pixels.
+    /// Scrollable area the button drives and watches.
     ///
-    /// Specifies the left position to scroll to (for horizontal scrolling).
-    /// Defaults to `0.0`.
-    #[props(default = 0.0)]
-    pub left: f64,
+    /// Can be the window, or an element (by ID, CSS selector or mounted node) with
+    /// its own overflow scrolling, such as a sidebar or a modal body. Both the scroll
+    /// action and the auto-hide logic use this container. Defaults to the window.
+    #[props(default)]
+    pub container: ReadOnlySignal<ScrollContainer>,
 
     /// Additional offset in pixels for the scroll target.
     ///
@@ -70,15 +95,15 @@ pub struct ScrollProps {
     ///
     /// When `true`, the scroll button will automatically appear or hide
     /// based on the user's current scroll position. Defaults to `true`.
-    #[props(default = true)]
-    pub auto_hide: bool,
+    #[props(default = ReadOnlySignal::new(Signal::new(true)))]
+    pub auto_hide: ReadOnlySignal<bool>,
 
     /// Scroll threshold in pixels for button visibility.
     ///
     /// Defines the vertical scroll position after which the scroll-to-top
     /// button becomes visible. Defaults to `20.0`.
-    #[props(default = 20.0)]
-    pub threshold: f64,
+    #[props(default = ReadOnlySignal::new(Signal::new(20.0)))]
+    pub threshold: ReadOnlySignal<f64>,
 
     /// Callback triggered when scrolling begins.
     ///
@@ -87,34 +112,101 @@ pub struct ScrollProps {
     #[props(default = Callback::default())]
     pub on_begin: Callback<(), ()>,
 
-    /// Callback triggered when scrolling ends.
+    /// Callback triggered once the scroll has actually finished.
+    ///
+    /// For smooth and animated scrolls this is when the destination is reached,
+    /// not when the scroll is issued, so it is safe to focus or highlight the
+    /// target here.
     ///
     /// Use this to handle actions like resetting states, analytics, or
     /// displaying notifications when the scrolling completes. Defaults to no-op.
     #[props(default = Callback::default())]
     pub on_end: Callback<(), ()>,
 
-    /// Update the URL hash during scrolling.
+    /// Callback triggered when a scroll is cancelled before it finishes.
     ///
-    /// When `true`, the browser's URL hash will be updated to reflect the
-    /// scroll target. Defaults to `true`.
-    #[props(default = true)]
-    pub update_hash: bool,
+    /// Wheel, touch or keyboard scrolling by the user, a second click on the
+    /// button, or unmounting the component cancels a pending or running scroll.
+    /// `on_end` is not triggered for cancelled scrolls. Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_cancel: Callback<(), ()>,
+
+    /// Callback triggered when a scroll cannot be carried out.
+    ///
+    /// Receives a `ScrollError` when the container or target element cannot be
+    /// found, a selector is invalid, or the browser refuses the URL update.
+    /// Neither `on_end` nor `on_cancel` is triggered for scrolls that never
+    /// start. Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_error: Callback<ScrollError, ()>,
+
+    /// How the URL is updated when scrolling to an element with an ID.
+    ///
+    /// `HashMode::Push` adds a history entry, `HashMode::Replace` rewrites the
+    /// current one, `HashMode::Query` sets a query parameter and
+    /// `HashMode::Custom` hands the ID to a callback. Use `HashMode::None` (or
+    /// `false`) to leave the URL alone. Defaults to `HashMode::Push`.
+    #[props(default = HashMode::Push, into)]
+    pub update_hash: HashMode,
 
     /// Target container ID for displaying the scroll button.
     ///
     /// When specified, the button will only be displayed if the scroll
     /// position of the container with the given ID equals to the current scroll y position.
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub show_id: &'static str,
+    /// Can be computed at runtime, e.g. per list item. Defaults to an empty string.
+    #[props(default)]
+    pub show_id: ReadOnlySignal<String>,
+
+    /// Accessible name of the button, read out by screen readers.
+    ///
+    /// Defaults to a description of the target, such as "Scroll to top" or
+    /// "Scroll to comments".
+    #[props(into)]
+    pub aria_label: Option<String>,
+
+    /// Tooltip shown when hovering the button.
+    ///
+    /// Defaults to the accessible name.
+    #[props(into)]
+    pub title: Option<String>,
 
-    /// Target container ID for scrolling actions.
+    /// Whether to move keyboard focus to an element target once the scroll has finished.
     ///
-    /// When specified, the scrolling will be applied to the container with
-    /// the given ID, instead of the default scrolling context (e.g. Scrolling to the top). Defaults to an empty string.
-    #[props(default = "")]
-    pub scroll_id: &'static str,
+    /// Elements that are not focusable on their own get `tabindex="-1"`, and focusing
+    /// never scrolls the page again. Keyboard and screen-reader users then continue from
+    /// where the page scrolled to. Defaults to `true`.
+    #[props(default = true)]
+    pub focus_target: bool,
+
+    /// How the button honours the user's `prefers-reduced-motion` setting.
+    ///
+    /// With `ReducedMotion::System`, smooth and animated scrolls jump straight to the
+    /// target while the operating system asks for reduced motion. Use `ReducedMotion::Never`
+    /// to keep `behavior` regardless, or `ReducedMotion::Always` to never animate.
+    /// Defaults to `ReducedMotion::System`.
+    #[props(default)]
+    pub reduced_motion: ReducedMotion,
+}
+
+impl From<Signal<Option<Rc<MountedData>>>> for ScrollTarget {
+    fn from(node: Signal<Option<Rc<MountedData>>>) -> Self {
+        ScrollTarget::Node(node_handle(node))
+    }
+}
+
+impl From<Signal<Option<Rc<MountedData>>>> for ScrollContainer {
+    fn from(node: Signal<Option<Rc<MountedData>>>) -> Self {
+        ScrollContainer::Node(node_handle(node))
+    }
+}
+
+fn node_handle(node: Signal<Option<Rc<MountedData>>>) -> NodeHandle {
+    NodeHandle::new(move || {
+        node.peek()
+            .as_ref()?
+            .downcast::<web_sys::Element>()
+            .cloned()
+    })
 }
 
 /// Scroll Component
@@ -127,26 +219,32 @@ pub struct ScrollProps {
 /// # Properties
 /// The component uses the `ScrollProps` struct for its properties. Key properties include:
 ///
-/// - **style**: Inline styles for the scroll button (`&'static str`). Default: `SCROLL_TO_TOP_STYLE`.
-/// - **class**: CSS classes for styling the button (`&'static str`). Default: `""`.
+/// - **style**: Inline styles for the scroll button (`String`). Default: `SCROLL_TO_TOP_STYLE`.
+/// - **class**: CSS classes for styling the button (`String`). Default: `""`.
 /// - **icon**: Custom icon for the scroll button (`Element`). Default: An internal SVG icon.
-/// - **behavior**: Scrolling behavior, either `Behavior::Smooth` or `Behavior::Instant`. Default: `Smooth`.
-/// - **top**: Vertical scroll target position (`f64`). Default: `0.0`.
-/// - **left**: Horizontal scroll target position (`f64`). Default: `0.0`.
+/// - **behavior**: Scrolling behavior: `Smooth`, `Instant`, `Auto` or `Animated { duration_ms, easing }`. Default: `Smooth`.
+/// - **target**: Where to scroll to (`ScrollTarget`). Default: the top of the page.
+/// - **container**: Scrollable area to drive and watch (`ReadOnlySignal<ScrollContainer>`). Default: the window.
 /// - **offset**: Additional offset for the scroll target (`f64`). Default: `0.0`.
 /// - **delay**: Delay before scrolling starts in milliseconds (`u32`). Default: `0`.
-/// - **auto_hide**: Whether the button is visible based on scroll position (`bool`). Default: `true`.
-/// - **threshold**: Scroll position threshold for visibility (`f64`). Default: `20.0`.
+/// - **auto_hide**: Whether the button is visible based on scroll position (`ReadOnlySignal<bool>`). Default: `true`.
+/// - **threshold**: Scroll position threshold for visibility (`ReadOnlySignal<f64>`). Default: `20.0`.
 /// - **on_begin**: Callback triggered when scrolling begins (`Callback<()>`). Default: No-op.
-/// - **on_end**: Callback triggered when scrolling ends (`Callback<()>`). Default: No-op.
-/// - **update_hash**: Whether to update the URL hash during scrolling (`bool`). Default: `true`.
-/// - **show_id**: ID of the container that determines the button's visibility (`&'static str`). Default: `""`.
-/// - **scroll_id**: ID of the target container for scrolling (`&'static str`). Default: `""`.
+/// - **on_end**: Callback triggered once scrolling has finished (`Callback<()>`). Default: No-op.
+/// - **on_cancel**: Callback triggered when a pending or running scroll is cancelled (`Callback<()>`). Default: No-op.
+/// - **on_error**: Callback triggered when the scroll cannot be carried out (`Callback<ScrollError>`). Default: No-op.
+/// - **update_hash**: How the URL is updated: `None`, `Push`, `Replace`, `Query` or `Custom` (`HashMode`). Default: `Push`.
+/// - **show_id**: ID of the container that determines the button's visibility (`ReadOnlySignal<String>`). Default: `""`.
+/// - **aria_label**: Accessible name of the button (`Option<String>`). Default: a description of the target.
+/// - **title**: Tooltip of the button (`Option<String>`). Default: the accessible name.
+/// - **focus_target**: Whether to focus an element target once the scroll has finished (`bool`). Default: `true`.
+/// - **reduced_motion**: How `prefers-reduced-motion` is honoured: `System`, `Always` or `Never` (`ReducedMotion`). Default: `System`.
 ///
 /// # Features
 /// - Automatically hides or shows based on scroll position.
+/// - Renders a native `<button type="button">`, so it is focusable, labelled and activated with Enter or Space.
 /// - Customizable appearance and content (e.g., SVG or HTML).
-/// - Supports smooth and instant scrolling behaviors.
+/// - Supports smooth, instant and custom-eased animated scrolling behaviors.
 /// - Configurable callbacks for when scrolling begins or ends.
 /// - Adjusts scrolling target with offsets and delays.
 ///
@@ -199,9 +297,29 @@ pub struct ScrollProps {
 /// }
 /// ```
 ///
+/// ## Custom Animation
+/// ```rust
+/// use scroll_rs::dioxus::Scroll;
+/// use scroll_rs::{Behavior, Easing};
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn AnimatedScrollButton() -> Element {
+///     rsx! {
+///         Scroll {
+///             behavior: Behavior::Animated {
+///                 duration_ms: 800,
+///                 easing: Easing::custom(|t| t * t),
+///             },
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Scroll to Specific Target
 /// ```rust
 /// use scroll_rs::dioxus::Scroll;
+/// use scroll_rs::ScrollTarget;
 /// use dioxus::prelude::*;
 ///
 /// #[component]
@@ -209,7 +327,7 @@ pub struct ScrollProps {
 ///     rsx! {
 ///         Scroll {
 ///             style: "position: fixed; bottom: 4rem; right: 3rem; background-color: #10B981; color: white; padding: 1rem; border-radius: 50%;",
-///             scroll_id: "bottom-scroll",
+///             target: ScrollTarget::id("bottom-scroll"),
 ///             icon: rsx! {
 ///                 svg {
 ///                     xmlns: "http://www.w3.org/2000/svg",
@@ -232,6 +350,7 @@ pub struct ScrollProps {
 /// ## Scroll to Left
 /// ```rust
 /// use scroll_rs::dioxus::Scroll;
+/// use scroll_rs::ScrollTarget;
 /// use dioxus::prelude::*;
 ///
 /// #[component]
@@ -239,7 +358,7 @@ pub struct ScrollProps {
 ///     rsx! {
 ///         Scroll {
 ///             style: "position: fixed; top: 40%; left: 2rem; background-color: #E11D48; color: white; padding: 1rem; border-radius: 50%;",
-///             left: -500.0,
+///             target: ScrollTarget::By { dx: -500.0, dy: 0.0 },
 ///             show_id: "left-scroll",
 ///             icon: rsx! {
 ///                 svg {
@@ -260,6 +379,48 @@ pub struct ScrollProps {
 /// }
 /// ```
 ///
+/// ## Scroll to a Mounted Node
+/// ```rust
+/// use scroll_rs::dioxus::Scroll;
+/// use scroll_rs::ScrollTarget;
+/// use dioxus::prelude::*;
+/// use std::rc::Rc;
+///
+/// #[component]
+/// pub fn ScrollToNode() -> Element {
+///     let mut details = use_signal(|| None::<Rc<MountedData>>);
+///     rsx! {
+///         section {
+///             onmounted: move |event| details.set(Some(event.data())),
+///             "Details"
+///         }
+///         Scroll { target: ScrollTarget::from(details) }
+///     }
+/// }
+/// ```
+///
+/// ## Inside a Scrollable Panel
+/// ```rust
+/// use scroll_rs::dioxus::Scroll;
+/// use scroll_rs::ScrollContainer;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Sidebar() -> Element {
+///     rsx! {
+///         aside {
+///             id: "sidebar",
+///             style: "height: 400px; overflow-y: auto;",
+///             div { style: "height: 2000px;" }
+///             Scroll {
+///                 container: ScrollContainer::id("sidebar"),
+///                 style: "position: sticky; bottom: 1rem; margin-left: auto; width: fit-content;",
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Scroll Visibility Based on Threshold
 /// ```rust
 /// use scroll_rs::dioxus::Scroll;
@@ -280,159 +441,63 @@ pub struct ScrollProps {
 ///
 /// # Behavior
 /// - The component uses an internal `use_signal` hook to manage the visibility of the scroll button.
-/// - It attaches a scroll event listener to monitor the current scroll position and determines visibility based on
+/// - It attaches a scroll event listener to the `container` to monitor its scroll position and determines visibility based on
 ///   the `threshold` or `show_id` container position.
 /// - Clicking the button triggers the scroll action, which can optionally include a delay and emit the `on_begin`
 ///   and `on_end` callbacks.
 ///
 /// # Notes
-/// - Ensure that `scroll_id` and `show_id` refer to valid element IDs in your DOM.
+/// - Ensure that element targets and `show_id` refer to elements in your DOM.
+/// - To render your own trigger, such as a design-system button, use [`HeadlessScroll`], which runs the same logic
+///   without any markup.
 /// - The `on_begin` and `on_end` callbacks allow you to handle actions when scrolling starts and ends, such as logging
 ///   or triggering animations.
 /// - The button will only be visible when the user has scrolled past the defined threshold or when the `show_id` container
 ///   is in view.
+/// - Safe to render on the server. The first render only depends on the props: the button starts hidden when
+///   `auto_hide` is on and shown otherwise, and the DOM is only touched from effects and event handlers, so hydration
+///   never mismatches.
 #[component]
 pub fn Scroll(props: ScrollProps) -> Element {
-    let mut is_visible = use_signal(|| false);
-
-    let container_element: Option<web_sys::Element> = if props.show_id.is_empty() {
-        None
-    } else {
-        window()
-            .expect("window not available")
-            .document()
-            .unwrap()
-            .get_element_by_id(props.show_id)
-    };
-
-    use_effect(move || {
-        let container_element = container_element.clone();
-        let threshold = props.threshold;
-        let auto_hide = props.auto_hide;
+    let ScrollProps {
+        style,
+        class,
+        icon,
+        title,
+        ..
+    } = props.clone();
 
-        if auto_hide {
-            let closure = Closure::new({
-                move || {
-                    let window = window().expect("window not available");
-                    let scroll_position = window.scroll_y().unwrap_or(0.0);
-
-                    if let Some(container) = container_element.as_ref() {
-                        let container_position = container.get_bounding_client_rect().top();
-                        is_visible.set(scroll_position > container_position);
-                    } else {
-                        is_visible.set(scroll_position > threshold);
+    rsx! {
+        HeadlessScroll {
+            render: move |trigger: ScrollTrigger| rsx! {
+                if trigger.visible {
+                    button {
+                        r#type: "button",
+                        class: class.clone(),
+                        style: style.clone(),
+                        aria_label: trigger.aria.label.clone(),
+                        title: title.clone().unwrap_or(trigger.aria.label),
+                        onclick: move |_| trigger.scroll.call(()),
+                        {icon.clone()}
                     }
                 }
-            });
-            let window = window().expect("window not available");
-
-            window
-                .add_event_listener_with_callback("scroll", closure.as_ref().unchecked_ref())
-                .expect("Failed to add scroll event listener");
-
-            closure.forget();
-        }
-
-        {
-            if auto_hide {
-                let window = window().expect("window not available");
-                let closure = Closure::wrap(Box::new(|| {}) as Box<dyn FnMut()>);
-                window
-                    .remove_event_listener_with_callback("scroll", closure.as_ref().unchecked_ref())
-                    .expect("Failed to remove scroll event listener");
-            }
-        }
-    });
-
-    let on_click = {
-        move |_| {
-            if props.delay > 0 {
-                let behavior = props.behavior.clone();
-                let top = props.top;
-                let left = props.left;
-                let offset = props.offset;
-                let update_hash = props.update_hash;
-                let scroll_id = props.scroll_id.to_string();
-                let on_begin = props.on_begin;
-                let on_end = props.on_end;
-                gloo::timers::callback::Timeout::new(props.delay, move || {
-                    on_begin.call(());
-                    scroll_to(
-                        top,
-                        left,
-                        offset,
-                        behavior,
-                        update_hash,
-                        Some(scroll_id.clone()),
-                    );
-                    on_end.call(());
-                })
-                .forget();
-            } else {
-                props.on_begin.call(());
-                scroll_to(
-                    props.top,
-                    props.left,
-                    props.offset,
-                    props.behavior.clone(),
-                    props.update_hash,
-                    Some(props.scroll_id.to_string()),
-                );
-                props.on_end.call(());
-            }
-        }
-    };
-
-    rsx! {
-        if is_visible() {
-            div {
-                class: props.class,
-                style: props.style,
-                onclick: on_click,
-                {props.icon}
-            }
-        }
-    }
-}
-
-/// Helper function to scroll to a specific position
-fn scroll_to(
-    top: f64,
-    left: f64,
-    offset: f64,
-    behavior: Behavior,
-    update_hash: bool,
-    scroll_id: Option<String>,
-) {
-    let options = ScrollToOptions::new();
-    options.set_left(left);
-    let window = window().expect("window not available");
-
-    match behavior {
-        Behavior::Auto => options.set_behavior(ScrollBehavior::Auto),
-        Behavior::Instant => options.set_behavior(ScrollBehavior::Instant),
-        Behavior::Smooth => options.set_behavior(ScrollBehavior::Smooth),
-    }
-
-    if let Some(container) = scroll_id
-        .clone()
-        .and_then(|id| window.document().unwrap().get_element_by_id(&id))
-    {
-        let container_position = container.get_bounding_client_rect().top();
-        options.set_top(container_position);
-    } else {
-        options.set_top(top + offset);
-    }
-
-    window.scroll_with_scroll_to_options(&options);
-
-    if update_hash {
-        if let Some(hash) = scroll_id {
-            let hash = format!("#{}", hash);
-            let _ = window
-                .history()
-                .unwrap()
-                .push_state_with_url(&JsValue::NULL, "", Some(&hash));
+            },
+            behavior: props.behavior,
+            target: props.target,
+            container: props.container,
+            offset: props.offset,
+            delay: props.delay,
+            auto_hide: props.auto_hide,
+            threshold: props.threshold,
+            on_begin: props.on_begin,
+            on_end: props.on_end,
+            on_cancel: props.on_cancel,
+            on_error: props.on_error,
+            update_hash: props.update_hash,
+            show_id: props.show_id,
+            aria_label: props.aria_label,
+            focus_target: props.focus_target,
+            reduced_motion: props.reduced_motion,
         }
     }
 }
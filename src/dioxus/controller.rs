@@ -0,0 +1,47 @@
+use crate::common::{ScrollController, ScrollOptions};
+use dioxus::prelude::*;
+
+/// Scrolls from your own code, e.g. after a form submit or on a keyboard
+/// shortcut.
+///
+/// Returns a [`ScrollController`] with `scroll_to`, `scroll_by`,
+/// `scroll_into_view`, `scroll_to_top`, `scroll_to_bottom` and `stop`. It
+/// scrolls exactly like the `Scroll` component, including its `on_begin`,
+/// `on_end`, `on_cancel` and `on_error` callbacks, which can be set on the
+/// controller. The latest `options` are used for every scroll, and a running
+/// scroll is cancelled when the component is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use scroll_rs::dioxus::use_scroll;
+/// use scroll_rs::{ScrollOptions, ScrollTarget};
+///
+/// #[component]
+/// pub fn Signup() -> Element {
+///     let scroll = use_scroll(ScrollOptions { offset: -64.0, ..Default::default() });
+///     rsx! {
+///         form {
+///             onsubmit: move |event| {
+///                 event.prevent_default();
+///                 scroll.scroll_to(ScrollTarget::id("confirmation"));
+///             },
+///             button { "Sign up" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_scroll(options: ScrollOptions) -> ScrollController {
+    let controller = use_hook(|| ScrollController::new(ScrollOptions::default()));
+    controller.set_options(options);
+
+    use_drop({
+        let controller = controller.clone();
+        move || {
+            controller.stop();
+        }
+    });
+
+    controller
+}
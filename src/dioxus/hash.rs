@@ -0,0 +1,40 @@
+use crate::common::{HashScroll, HashScrollOptions};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Scrolls to the element named by the URL hash on mount and on every
+/// `hashchange`.
+///
+/// Shared links such as `/docs#install` then land on the section with the
+/// configured `offset` and `behavior` applied, even when the section is
+/// rendered after the page loads. The listener is recreated whenever `options`
+/// change and is removed when the component is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::use_scroll_to_hash;
+/// use scroll_rs::HashScrollOptions;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Docs() -> Element {
+///     use_scroll_to_hash(HashScrollOptions { offset: -64.0, ..Default::default() });
+///     rsx! { section { id: "install", "Install" } }
+/// }
+/// ```
+pub fn use_scroll_to_hash(options: HashScrollOptions) {
+    let hash_scroll = use_hook(|| Rc::new(RefCell::new(None::<HashScroll>)));
+
+    use_effect(use_reactive((&options,), {
+        let hash_scroll = hash_scroll.clone();
+        move |(options,)| {
+            *hash_scroll.borrow_mut() = HashScroll::start(options);
+        }
+    }));
+
+    use_drop(move || {
+        hash_scroll.borrow_mut().take();
+    });
+}
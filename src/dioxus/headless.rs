@@ -0,0 +1,235 @@
+use crate::common::{
+    Behavior, HashMode, ReducedMotion, ScrollAria, ScrollContainer, ScrollEngine, ScrollError,
+    ScrollListener, ScrollOptions, ScrollTarget,
+};
+use crate::dioxus::use_scroll;
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// State handed to the render function of [`HeadlessScroll`].
+#[derive(Clone, PartialEq)]
+pub struct ScrollTrigger {
+    /// Whether the trigger should be shown, following `auto_hide`, `threshold`
+    /// and `show_id`.
+    pub visible: bool,
+    /// How far the container has been scrolled through, from `0.0` to `1.0`.
+    pub progress: f64,
+    /// Whether a scroll is pending or running.
+    pub is_scrolling: bool,
+    /// Starts the scroll, or cancels the one in progress, exactly like a click
+    /// on the `Scroll` button.
+    pub scroll: Callback<(), ()>,
+    /// ARIA attributes describing the trigger.
+    pub aria: ScrollAria,
+}
+
+/// Properties for configuring the `HeadlessScroll` component.
+///
+/// These are the props of `Scroll` that control visibility and scrolling.
+/// Everything visual is left to `render`.
+#[derive(Props, Clone, PartialEq)]
+pub struct HeadlessScrollProps {
+    /// Renders the trigger from the current [`ScrollTrigger`] state.
+    ///
+    /// Called on every change, including while the trigger is hidden, so it can
+    /// animate out instead of disappearing.
+    pub render: Callback<ScrollTrigger, Element>,
+
+    /// Behavior of the scroll action. Defaults to `Behavior::Smooth`.
+    #[props(default = Behavior::Smooth)]
+    pub behavior: Behavior,
+
+    /// Target of the scroll action. Defaults to the top of the page.
+    #[props(default = ScrollTarget::default())]
+    pub target: ScrollTarget,
+
+    /// Scrollable area the trigger drives and watches. Defaults to the window.
+    #[props(default)]
+    pub container: ReadOnlySignal<ScrollContainer>,
+
+    /// Additional offset in pixels for the scroll target. Defaults to `0.0`.
+    #[props(default = 0.0)]
+    pub offset: f64,
+
+    /// Delay before initiating the scroll action, in milliseconds. Defaults to `0`.
+    #[props(default = 0)]
+    pub delay: u32,
+
+    /// Whether `visible` follows the scroll position. Defaults to `true`.
+    #[props(default = ReadOnlySignal::new(Signal::new(true)))]
+    pub auto_hide: ReadOnlySignal<bool>,
+
+    /// Scroll position in pixels after which the trigger becomes visible.
+    /// Defaults to `20.0`.
+    #[props(default = ReadOnlySignal::new(Signal::new(20.0)))]
+    pub threshold: ReadOnlySignal<f64>,
+
+    /// Callback triggered when scrolling begins. Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_begin: Callback<(), ()>,
+
+    /// Callback triggered once the scroll has actually finished. Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_end: Callback<(), ()>,
+
+    /// Callback triggered when a scroll is cancelled before it finishes.
+    /// Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_cancel: Callback<(), ()>,
+
+    /// Callback triggered when a scroll cannot be carried out. Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_error: Callback<ScrollError, ()>,
+
+    /// How the URL is updated when scrolling to an element with an ID.
+    /// Defaults to `HashMode::Push`.
+    #[props(default = HashMode::Push, into)]
+    pub update_hash: HashMode,
+
+    /// ID of a container whose scroll position controls visibility.
+    /// Defaults to an empty string.
+    #[props(default)]
+    pub show_id: ReadOnlySignal<String>,
+
+    /// Accessible name of the trigger, handed out as `aria.label`.
+    /// Defaults to a description of the target, such as "Scroll to top".
+    #[props(into)]
+    pub aria_label: Option<String>,
+
+    /// Whether to move keyboard focus to element targets once the scroll has
+    /// finished. Defaults to `true`.
+    #[props(default = true)]
+    pub focus_target: bool,
+
+    /// Whether smooth and animated scrolls become instant when the user
+    /// prefers reduced motion. Defaults to `ReducedMotion::System`.
+    #[props(default)]
+    pub reduced_motion: ReducedMotion,
+}
+
+/// HeadlessScroll Component
+///
+/// The logic of [`Scroll`](crate::dioxus::Scroll) without any markup: it tracks
+/// visibility, progress and the running scroll, and hands them to `render`
+/// together with a `scroll` action and ARIA attributes. Use it to turn a button
+/// from your own design system into a scroll trigger.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use scroll_rs::dioxus::{HeadlessScroll, ScrollTrigger};
+///
+/// #[component]
+/// pub fn BackToTop() -> Element {
+///     rsx! {
+///         HeadlessScroll {
+///             render: move |trigger: ScrollTrigger| rsx! {
+///                 button {
+///                     class: if trigger.visible { "fab fab-visible" } else { "fab" },
+///                     aria_label: trigger.aria.label,
+///                     aria_hidden: trigger.aria.hidden,
+///                     aria_busy: trigger.aria.busy,
+///                     onclick: move |_| trigger.scroll.call(()),
+///                     "{trigger.progress * 100.0:.0}%"
+///                 }
+///             },
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn HeadlessScroll(props: HeadlessScrollProps) -> Element {
+    let mut visible = use_signal(|| !*props.auto_hide.peek());
+    let mut progress = use_signal(|| 0.0);
+    let mut is_scrolling = use_signal(|| false);
+    let listener = use_hook(|| Rc::new(RefCell::new(None::<ScrollListener>)));
+
+    use_effect({
+        let listener = listener.clone();
+        move || {
+            let auto_hide = (props.auto_hide)();
+            let threshold = (props.threshold)();
+            let show_id = (props.show_id)();
+            // Drop the previous listener before subscribing again. The last one
+            // is dropped together with the component's hooks.
+            listener.borrow_mut().take();
+            if !auto_hide && !*visible.peek() {
+                visible.set(true);
+            }
+            if let Ok(engine) = ScrollEngine::with_container(&props.container.read()) {
+                let mut update = move |engine: &ScrollEngine| {
+                    // Only write on change, so scrolling does not re-render on
+                    // every frame.
+                    let is_visible = !auto_hide || engine.is_past(threshold, &show_id);
+                    if *visible.peek() != is_visible {
+                        visible.set(is_visible);
+                    }
+                    let value = engine.progress(None);
+                    if *progress.peek() != value {
+                        progress.set(value);
+                    }
+                };
+                update(&engine);
+                let root = engine.root().clone();
+                *listener.borrow_mut() = Some(root.on_scroll(move || update(&engine)));
+            }
+        }
+    });
+
+    let controller = use_scroll(ScrollOptions {
+        container: props.container.read().clone(),
+        offset: props.offset,
+        behavior: props.behavior.clone(),
+        update_hash: props.update_hash.clone(),
+        delay_ms: props.delay,
+        focus_target: props.focus_target,
+        reduced_motion: props.reduced_motion,
+    });
+    let on_begin = props.on_begin;
+    let on_end = props.on_end;
+    let on_cancel = props.on_cancel;
+    let on_error = props.on_error;
+    controller
+        .on_begin(move || on_begin.call(()))
+        .on_end(move || {
+            let mut is_scrolling = is_scrolling;
+            is_scrolling.set(false);
+            on_end.call(());
+        })
+        .on_cancel(move || {
+            let mut is_scrolling = is_scrolling;
+            is_scrolling.set(false);
+            on_cancel.call(());
+        })
+        .on_error(move |error| {
+            let mut is_scrolling = is_scrolling;
+            is_scrolling.set(false);
+            on_error.call(error);
+        });
+
+    let scroll = {
+        let target = props.target.clone();
+        use_callback(move |()| {
+            // A second call while a scroll is pending or running cancels it.
+            if !controller.stop() {
+                let task = controller.scroll_to(target.clone());
+                is_scrolling.set(task.is_running());
+            }
+        })
+    };
+
+    let mut aria = ScrollAria::new(&props.target, visible(), is_scrolling());
+    if let Some(label) = props.aria_label {
+        aria.label = label;
+    }
+
+    props.render.call(ScrollTrigger {
+        visible: visible(),
+        progress: progress(),
+        is_scrolling: is_scrolling(),
+        scroll,
+        aria,
+    })
+}
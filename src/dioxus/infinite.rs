@@ -0,0 +1,132 @@
+use crate::common::{ScrollContainer, SentinelObserver, SENTINEL_ROOT_MARGIN};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Properties for configuring the `InfiniteScroll` component.
+///
+/// The component renders its children followed by an invisible sentinel and
+/// asks for more content when the sentinel nears the visible area.
+#[derive(Props, Clone, PartialEq)]
+pub struct InfiniteScrollProps {
+    /// The items loaded so far.
+    #[props(default = VNode::empty())]
+    pub children: Element,
+
+    /// Callback triggered when the end of the list comes into reach.
+    ///
+    /// Not triggered while `loading` is `true` or once `has_more` is `false`.
+    pub on_reach_end: Callback<(), ()>,
+
+    /// Whether a page is currently being fetched.
+    ///
+    /// Set this to `true` from `on_reach_end` and back to `false` once the page
+    /// has been appended to prevent duplicate requests. Defaults to `false`.
+    #[props(default = false)]
+    pub loading: bool,
+
+    /// Whether there is more content to load.
+    ///
+    /// Defaults to `true`.
+    #[props(default = true)]
+    pub has_more: bool,
+
+    /// `IntersectionObserver` root margin, in CSS shorthand.
+    ///
+    /// Defaults to `"0px 0px 200px 0px"`, which fires 200px before the end of
+    /// the list becomes visible.
+    #[props(default = SENTINEL_ROOT_MARGIN.to_string())]
+    pub root_margin: String,
+
+    /// Scrollable area the list lives in.
+    ///
+    /// Defaults to the window.
+    #[props(default = ScrollContainer::default())]
+    pub container: ScrollContainer,
+}
+
+/// InfiniteScroll Component
+///
+/// A Dioxus component for feeds that load page by page. It renders its children followed by a
+/// sentinel element watched by an `IntersectionObserver`, and calls `on_reach_end` when the
+/// sentinel enters the visible area of the window or the configured container. While `loading` is
+/// `true` or `has_more` is `false` the sentinel is not observed, so each page is requested once.
+/// If the list is still too short to fill the container after a page loads, the next page is
+/// requested right away.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::InfiniteScroll;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Feed() -> Element {
+///     let mut items = use_signal(|| (0..20).collect::<Vec<u32>>());
+///     let mut loading = use_signal(|| false);
+///
+///     rsx! {
+///         InfiniteScroll {
+///             loading: loading(),
+///             has_more: items.len() < 200,
+///             on_reach_end: move |_| {
+///                 loading.set(true);
+///                 spawn(async move {
+///                     let page = fetch_page(items.len()).await;
+///                     items.extend(page);
+///                     loading.set(false);
+///                 });
+///             },
+///             for item in items.iter() {
+///                 p { "{item}" }
+///             }
+///         }
+///     }
+/// }
+/// # async fn fetch_page(from: usize) -> Vec<u32> { (from as u32..from as u32 + 20).collect() }
+/// ```
+#[component]
+pub fn InfiniteScroll(props: InfiniteScrollProps) -> Element {
+    let mut sentinel = use_signal(|| None::<Rc<MountedData>>);
+    let observer = use_hook(|| Rc::new(RefCell::new(None::<SentinelObserver>)));
+    let on_reach_end = props.on_reach_end;
+
+    use_effect(use_reactive(
+        (
+            &props.container,
+            &props.root_margin,
+            &props.loading,
+            &props.has_more,
+        ),
+        {
+            let observer = observer.clone();
+            move |(container, root_margin, loading, has_more)| {
+                let element = sentinel
+                    .read()
+                    .as_ref()
+                    .and_then(|node| node.downcast::<web_sys::Element>().cloned());
+                *observer.borrow_mut() = match element {
+                    Some(element) if !loading && has_more => {
+                        SentinelObserver::observe(&element, &container, &root_margin, move || {
+                            on_reach_end.call(())
+                        })
+                    }
+                    _ => None,
+                };
+            }
+        },
+    ));
+
+    use_drop(move || {
+        observer.borrow_mut().take();
+    });
+
+    rsx! {
+        {props.children}
+        div {
+            aria_hidden: "true",
+            style: "height: 1px;",
+            onmounted: move |event| sentinel.set(Some(event.data())),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::common::{ScrollLock, ScrollLockOptions};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Locks the page scroll while `locked` is `true`.
+///
+/// Meant for modals and drawers: the page behind them stops scrolling while
+/// the elements in `options.allow` keep scrolling. Nested locks are reference
+/// counted, so the page is only unlocked once every component holding a lock
+/// has released it. The lock is also released when the component is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::use_scroll_lock;
+/// use scroll_rs::{ScrollContainer, ScrollLockOptions};
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Modal(open: bool, children: Element) -> Element {
+///     use_scroll_lock(
+///         open,
+///         ScrollLockOptions {
+///             allow: vec![ScrollContainer::id("modal-body")],
+///             ..Default::default()
+///         },
+///     );
+///     rsx! {
+///         if open {
+///             div { class: "modal", div { id: "modal-body", {children} } }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_scroll_lock(locked: bool, options: ScrollLockOptions) {
+    let lock = use_hook(|| Rc::new(RefCell::new(None::<ScrollLock>)));
+
+    use_effect(use_reactive((&locked, &options), {
+        let lock = lock.clone();
+        move |(locked, options)| {
+            let next = locked.then(|| ScrollLock::acquire(&options));
+            *lock.borrow_mut() = next;
+        }
+    }));
+
+    use_drop(move || {
+        lock.borrow_mut().take();
+    });
+}
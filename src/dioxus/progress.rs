@@ -0,0 +1,148 @@
+use crate::common::{
+    ScrollContainer, ScrollEngine, ScrollListener, ScrollTarget, SCROLL_PROGRESS_STYLE,
+};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Properties for configuring the `ScrollProgress` component.
+///
+/// The component renders a bar whose width follows how far the window, a
+/// scrollable container or a single element has been scrolled through.
+#[derive(Props, Clone, PartialEq)]
+pub struct ScrollProgressProps {
+    /// Custom inline styles for the progress bar.
+    ///
+    /// Accepts any string, so styles can be computed at runtime. The bar's
+    /// `width` is appended to these styles on every update.
+    /// Defaults to the built-in `SCROLL_PROGRESS_STYLE`.
+    #[props(default = SCROLL_PROGRESS_STYLE.to_string(), into)]
+    pub style: String,
+
+    /// Custom CSS classes for the progress bar.
+    ///
+    /// Accepts any string. Defaults to an empty string.
+    #[props(default, into)]
+    pub class: String,
+
+    /// Scrollable area whose progress is measured.
+    ///
+    /// Defaults to the window.
+    #[props(default = ScrollContainer::default())]
+    pub container: ScrollContainer,
+
+    /// Element to measure progress through, such as an article.
+    ///
+    /// Progress starts when the element's top reaches the top of the container
+    /// and completes when its bottom comes into view. Defaults to `None`, which
+    /// measures the whole container.
+    #[props(default = None)]
+    pub element: Option<ScrollTarget>,
+
+    /// Callback triggered with the new progress (`0.0..=1.0`) whenever it changes.
+    ///
+    /// Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_change: Callback<f64, ()>,
+}
+
+/// ScrollProgress Component
+///
+/// A Dioxus component that renders a reading-progress bar. The bar fills from `0%` to `100%` as
+/// the window, a scrollable container or a single element is scrolled through. Use
+/// [`use_scroll_progress`] instead to render the progress in any other way.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::ScrollProgress;
+/// use scroll_rs::ScrollTarget;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Post() -> Element {
+///     rsx! {
+///         ScrollProgress { element: ScrollTarget::id("article") }
+///         article { id: "article", class: "h-[3000px]" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ScrollProgress(props: ScrollProgressProps) -> Element {
+    let progress = use_progress(
+        props.container.clone(),
+        props.element.clone(),
+        props.on_change,
+    );
+    let percent = progress() * 100.0;
+
+    rsx! {
+        div {
+            class: props.class,
+            style: "{props.style} width: {percent}%;",
+            role: "progressbar",
+            aria_valuemin: "0",
+            aria_valuemax: "100",
+            aria_valuenow: "{percent.round()}",
+        }
+    }
+}
+
+/// Tracks how far `container`, or `element` within it, has been scrolled
+/// through.
+///
+/// Returns a signal holding the progress from `0.0` to `1.0`. The listener is
+/// recreated whenever `container` or `element` change and is removed when the
+/// component is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::use_scroll_progress;
+/// use scroll_rs::ScrollContainer;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Percent() -> Element {
+///     let progress = use_scroll_progress(ScrollContainer::Window, None);
+///     rsx! { span { "{progress() * 100.0:.0}%" } }
+/// }
+/// ```
+pub fn use_scroll_progress(
+    container: ScrollContainer,
+    element: Option<ScrollTarget>,
+) -> Signal<f64> {
+    use_progress(container, element, Callback::default())
+}
+
+fn use_progress(
+    container: ScrollContainer,
+    element: Option<ScrollTarget>,
+    on_change: Callback<f64, ()>,
+) -> Signal<f64> {
+    let mut progress = use_signal(|| 0.0);
+    let listener = use_hook(|| Rc::new(RefCell::new(None::<ScrollListener>)));
+
+    use_effect(use_reactive((&container, &element), {
+        let listener = listener.clone();
+        move |(container, element)| {
+            *listener.borrow_mut() = ScrollEngine::with_container(&container).ok().map(|engine| {
+                progress.set(engine.progress(element.as_ref()));
+                let root = engine.root().clone();
+                root.on_scroll(move || {
+                    let value = engine.progress(element.as_ref());
+                    if *progress.peek() != value {
+                        progress.set(value);
+                        on_change.call(value);
+                    }
+                })
+            });
+        }
+    }));
+
+    use_drop(move || {
+        listener.borrow_mut().take();
+    });
+
+    progress
+}
@@ -0,0 +1,55 @@
+use crate::common::{ScrollRestoration, ScrollRestorationOptions};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Saves the scroll position of every history entry and restores it on
+/// back/forward navigation.
+///
+/// Pass a key that identifies the current route, such as the current route
+/// from the Dioxus router formatted with `to_string()`, and call the hook once
+/// near the root of the app. Fresh navigations scroll to the URL hash target,
+/// or to the top when there is none. The saved positions live as long as the
+/// calling component.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::use_scroll_restoration;
+/// use scroll_rs::ScrollRestorationOptions;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Layout(path: String, children: Element) -> Element {
+///     use_scroll_restoration(path, ScrollRestorationOptions::default());
+///     children
+/// }
+/// ```
+pub fn use_scroll_restoration(route: String, options: ScrollRestorationOptions) {
+    let restoration = use_hook(|| Rc::new(RefCell::new(None::<ScrollRestoration>)));
+
+    use_effect(use_reactive((&options,), {
+        let restoration = restoration.clone();
+        let route = route.clone();
+        move |(options,)| {
+            let created = ScrollRestoration::new(options);
+            if let Some(created) = &created {
+                created.navigate(&route);
+            }
+            *restoration.borrow_mut() = created;
+        }
+    }));
+
+    use_effect(use_reactive((&route,), {
+        let restoration = restoration.clone();
+        move |(route,)| {
+            if let Some(restoration) = restoration.borrow().as_ref() {
+                restoration.navigate(&route);
+            }
+        }
+    }));
+
+    use_drop(move || {
+        restoration.borrow_mut().take();
+    });
+}
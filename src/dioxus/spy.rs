@@ -0,0 +1,148 @@
+use crate::common::{ScrollContainer, ScrollSpyObserver, ScrollSpyOptions};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Properties for configuring the `ScrollSpy` component.
+///
+/// The component tracks which of a list of sections is currently in view and
+/// reports changes through `on_change`, e.g. to highlight navigation links.
+#[derive(Props, Clone, PartialEq)]
+pub struct ScrollSpyProps {
+    /// IDs of the sections to track, in document order.
+    pub ids: Vec<String>,
+
+    /// Offset in pixels added to the top of the observed area.
+    ///
+    /// Uses the same convention as `Scroll`'s `offset`: pass the negative height
+    /// of a fixed header so sections hidden behind it are ignored. Defaults to `0.0`.
+    #[props(default = 0.0)]
+    pub offset: f64,
+
+    /// `IntersectionObserver` root margin, in CSS shorthand.
+    ///
+    /// Defaults to `"0px 0px -50% 0px"`, i.e. the upper half of the viewport.
+    #[props(default = ScrollSpyOptions::default().root_margin)]
+    pub root_margin: String,
+
+    /// Fraction of a section that must be visible for it to count as in view.
+    ///
+    /// Defaults to `0.0`.
+    #[props(default = 0.0)]
+    pub threshold: f64,
+
+    /// Scrollable area the sections live in.
+    ///
+    /// Defaults to the window.
+    #[props(default = ScrollContainer::default())]
+    pub container: ScrollContainer,
+
+    /// Callback triggered with the new active section ID whenever it changes.
+    ///
+    /// Defaults to no-op.
+    #[props(default = Callback::default())]
+    pub on_change: Callback<Option<String>, ()>,
+
+    /// Content rendered as-is inside the spy.
+    #[props(default = VNode::empty())]
+    pub children: Element,
+}
+
+/// ScrollSpy Component
+///
+/// A Dioxus component that tracks which section of a long page is currently in view using an
+/// `IntersectionObserver`, and calls `on_change` with the active section ID. It renders its
+/// children unchanged, so it can wrap a navigation menu or sit anywhere in the tree.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::ScrollSpy;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Docs() -> Element {
+///     let mut active = use_signal(|| None::<String>);
+///     let ids = vec!["intro".to_string(), "install".to_string(), "usage".to_string()];
+///
+///     rsx! {
+///         ScrollSpy {
+///             ids: ids.clone(),
+///             offset: -64.0,
+///             on_change: move |id| active.set(id),
+///             nav {
+///                 for id in ids {
+///                     a {
+///                         href: "#{id}",
+///                         class: if active().as_deref() == Some(id.as_str()) { "active" },
+///                         "{id}"
+///                     }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ScrollSpy(props: ScrollSpyProps) -> Element {
+    let options = ScrollSpyOptions {
+        offset: props.offset,
+        root_margin: props.root_margin.clone(),
+        threshold: props.threshold,
+        container: props.container.clone(),
+    };
+    use_spy(props.ids.clone(), options, props.on_change);
+
+    props.children
+}
+
+/// Tracks which of the sections with the given `ids` is currently in view.
+///
+/// Returns a signal holding the ID of the active section, or `None` until a
+/// section scrolls into view. The observer is recreated whenever `ids` or
+/// `options` change and is disconnected when the component is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::use_scroll_spy;
+/// use scroll_rs::ScrollSpyOptions;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Toc() -> Element {
+///     let active = use_scroll_spy(
+///         vec!["intro".into(), "usage".into()],
+///         ScrollSpyOptions { offset: -64.0, ..Default::default() },
+///     );
+///     rsx! { p { "Reading: {active().unwrap_or_default()}" } }
+/// }
+/// ```
+pub fn use_scroll_spy(ids: Vec<String>, options: ScrollSpyOptions) -> Signal<Option<String>> {
+    use_spy(ids, options, Callback::default())
+}
+
+fn use_spy(
+    ids: Vec<String>,
+    options: ScrollSpyOptions,
+    on_change: Callback<Option<String>, ()>,
+) -> Signal<Option<String>> {
+    let mut active = use_signal(|| None);
+    let observer = use_hook(|| Rc::new(RefCell::new(None::<ScrollSpyObserver>)));
+
+    use_effect(use_reactive((&ids, &options), {
+        let observer = observer.clone();
+        move |(ids, options)| {
+            *observer.borrow_mut() = ScrollSpyObserver::observe(&ids, &options, move |id| {
+                active.set(id.clone());
+                on_change.call(id);
+            });
+        }
+    }));
+
+    use_drop(move || {
+        observer.borrow_mut().take();
+    });
+
+    active
+}
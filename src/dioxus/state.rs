@@ -0,0 +1,82 @@
+use crate::common::{ScrollDirection, ScrollState, ScrollStateObserver, ScrollStateOptions};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Tracks the position and movement of the window or a scrollable container.
+///
+/// Returns a signal holding the current [`ScrollState`]: position, direction,
+/// velocity and whether the root is at the top or bottom. The signal is only
+/// written when the state changes. The listener is recreated whenever
+/// `options` change and is removed when the component is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::use_scroll_state;
+/// use scroll_rs::ScrollStateOptions;
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Position() -> Element {
+///     let state = use_scroll_state(ScrollStateOptions::default());
+///     let state = state();
+///     rsx! { p { "{state.top:.0}px, {state.direction:?} at {state.velocity:.0}px/s" } }
+/// }
+/// ```
+pub fn use_scroll_state(options: ScrollStateOptions) -> Signal<ScrollState> {
+    let mut state = use_signal(ScrollState::default);
+    use_observer(options, move |next| state.set(next));
+    state
+}
+
+/// Tracks which way the user last scrolled.
+///
+/// Returns a signal holding [`ScrollDirection::Idle`] until the root first
+/// moves further than the tolerance. Unlike [`ScrollState::direction`], the
+/// last direction is kept once scrolling settles, which is what a header that
+/// hides on the way down and reappears on the way up needs.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::dioxus::use_scroll_direction;
+/// use scroll_rs::{ScrollDirection, ScrollStateOptions};
+/// use dioxus::prelude::*;
+///
+/// #[component]
+/// pub fn Header() -> Element {
+///     let direction = use_scroll_direction(ScrollStateOptions::default());
+///     let hidden = direction() == ScrollDirection::Down;
+///     rsx! {
+///         header {
+///             style: if hidden { "transform: translateY(-100%);" } else { "transform: none;" },
+///             "My Site"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_scroll_direction(options: ScrollStateOptions) -> Signal<ScrollDirection> {
+    let mut direction = use_signal(ScrollDirection::default);
+    use_observer(options, move |state| {
+        if state.direction != ScrollDirection::Idle && *direction.peek() != state.direction {
+            direction.set(state.direction);
+        }
+    });
+    direction
+}
+
+fn use_observer(options: ScrollStateOptions, on_change: impl FnMut(ScrollState) + Clone + 'static) {
+    let observer = use_hook(|| Rc::new(RefCell::new(None::<ScrollStateObserver>)));
+
+    use_effect(use_reactive((&options,), {
+        let observer = observer.clone();
+        move |(options,)| {
+            *observer.borrow_mut() = ScrollStateObserver::observe(&options, on_change.clone());
+        }
+    }));
+
+    use_drop(move || {
+        observer.borrow_mut().take();
+    });
+}
@@ -0,0 +1,32 @@
+use crate::common::testing::{assert_released, live_subscribers, settle};
+use crate::dioxus::{Scroll, ScrollProgress};
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn page() -> Element {
+    rsx! {
+        Scroll { auto_hide: true }
+        ScrollProgress {}
+    }
+}
+
+#[wasm_bindgen_test]
+async fn remounting_does_not_accumulate_listeners() {
+    assert_released();
+    for _ in 0..20 {
+        let mut dom = VirtualDom::new(page);
+        dom.rebuild_in_place();
+        // Effects run once the renderer has applied the first render; they
+        // may render again and queue more effects.
+        for _ in 0..3 {
+            dom.render_immediate(&mut NoOpMutations);
+        }
+        settle().await;
+        assert_eq!(live_subscribers(), 2);
+
+        drop(dom);
+        settle().await;
+        assert_released();
+    }
+}
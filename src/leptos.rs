@@ -1,13 +1,78 @@
-use crate::common::SCROLL_TO_TOP_STYLE;
-use crate::Behavior;
-use leptos::{
-    ev::{scroll, MouseEvent},
-    prelude::*,
-    *,
+mod controller;
+mod hash;
+mod headless;
+mod infinite;
+mod lock;
+mod progress;
+mod restore;
+mod spy;
+mod state;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests;
+
+pub use controller::use_scroll;
+pub use hash::use_scroll_to_hash;
+pub use headless::{HeadlessScroll, ScrollTrigger};
+pub use infinite::InfiniteScroll;
+pub use lock::use_scroll_lock;
+pub use progress::{use_scroll_progress, ScrollProgress};
+pub use restore::use_scroll_restoration;
+pub use spy::{use_scroll_spy, ScrollSpy};
+pub use state::{use_scroll_direction, use_scroll_state};
+
+use crate::common::{
+    HashMode, NodeHandle, ReducedMotion, ScrollContainer, ScrollError, ScrollTarget,
+    SCROLL_TO_TOP_STYLE,
 };
-use std::time::Duration;
-use wasm_bindgen::JsValue;
-use web_sys::{ScrollBehavior, ScrollToOptions};
+use crate::Behavior;
+use leptos::{html::ElementType, prelude::*};
+use wasm_bindgen::JsCast;
+
+impl<E> From<NodeRef<E>> for ScrollTarget
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    fn from(node: NodeRef<E>) -> Self {
+        ScrollTarget::Node(node_handle(node))
+    }
+}
+
+impl<E> From<NodeRef<E>> for ScrollContainer
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    fn from(node: NodeRef<E>) -> Self {
+        ScrollContainer::Node(node_handle(node))
+    }
+}
+
+fn node_handle<E>(node: NodeRef<E>) -> NodeHandle
+where
+    E: ElementType + 'static,
+    E::Output: JsCast + Clone + 'static,
+{
+    NodeHandle::new(move || node.get_untracked().map(JsCast::unchecked_into))
+}
+
+/// Runs `effect` like [`Effect::new`] and keeps the listener or observer it returns alive.
+///
+/// The previous value is dropped before every re-run, and the last one as soon as the owner is
+/// cleaned up, so unmounting removes DOM listeners right away instead of whenever the effect itself
+/// is disposed.
+pub(crate) fn use_owned_effect<T: 'static>(mut effect: impl FnMut() -> Option<T> + 'static) {
+    let owned = StoredValue::new_local(None::<T>);
+
+    Effect::new(move |_| {
+        owned.set_value(None);
+        owned.set_value(effect());
+    });
+
+    on_cleanup(move || {
+        owned.try_set_value(None);
+    });
+}
 
 /// Scroll Component
 ///
@@ -19,109 +84,123 @@ use web_sys::{ScrollBehavior, ScrollToOptions};
 /// # Properties
 /// The `Scroll` component accepts a set of properties to customize its appearance and behavior:
 ///
-/// - **style**: Inline CSS styles for the scroll button (`&'static str`). Default: `SCROLL_TO_TOP_STYLE`.
-/// - **class**: Additional CSS classes for the scroll button (`&'static str`). Default: `""`.
-/// - **icon**: Custom icon for the scroll button (`&'static str`). Default: `"↑"`.
-/// - **behavior**: Scrolling behavior (`Behavior`). Options: `Behavior::Smooth` or `Behavior::Instant`. Default: `Smooth`.
-/// - **top**: Vertical scroll target position in pixels (`f64`). Default: `0.0`.
-/// - **left**: Horizontal scroll target position in pixels (`f64`). Default: `0.0`.
-/// - **offset**: Additional offset for the scroll target in pixels (`f64`). Default: `0.0`.
-/// - **delay**: Delay before initiating scrolling, in milliseconds (`u64`). Default: `0`.
-/// - **auto_hide**: Toggles automatic visibility based on scroll position (`bool`). Default: `true`.
-/// - **threshold**: Scroll position threshold for button visibility, in pixels (`f64`). Default: `20.0`.
+/// - **style**: Inline CSS styles for the scroll button (`Signal<String>`). Default: `SCROLL_TO_TOP_STYLE`.
+/// - **class**: Additional CSS classes for the scroll button (`Signal<String>`). Default: `""`.
+/// - **icon**: Custom icon for the scroll button (`ViewFn`). Default: An internal SVG icon.
+/// - **behavior**: Scrolling behavior (`Signal<Behavior, LocalStorage>`). Options: `Smooth`, `Instant`, `Auto` or `Animated { duration_ms, easing }`. Default: `Smooth`.
+/// - **target**: Where to scroll to (`Signal<ScrollTarget, LocalStorage>`). Default: the top of the page.
+/// - **container**: Scrollable area to drive and watch (`Signal<ScrollContainer, LocalStorage>`). Default: the window.
+/// - **offset**: Additional offset for the scroll target in pixels (`Signal<f64>`). Default: `0.0`.
+/// - **delay**: Delay before initiating scrolling, in milliseconds (`Signal<u64>`). Default: `0`.
+/// - **auto_hide**: Toggles automatic visibility based on scroll position (`Signal<bool>`). Default: `true`.
+/// - **threshold**: Scroll position threshold for button visibility, in pixels (`Signal<f64>`). Default: `20.0`.
 /// - **on_begin**: Callback triggered when scrolling begins (`Callback<()>`). Default: No-op.
-/// - **on_end**: Callback triggered when scrolling ends (`Callback<()>`). Default: No-op.
-/// - **update_hash**: Whether to update the URL hash during scrolling (`bool`). Default: `true`.
-/// - **show_id**: ID of a container controlling the button's visibility (`&'static str`). Default: `""`.
-/// - **scroll_id**: ID of the target container to scroll to (`&'static str`). Default: `""`.
+/// - **on_end**: Callback triggered once scrolling has finished (`Callback<()>`). Default: No-op.
+/// - **on_cancel**: Callback triggered when a pending or running scroll is cancelled (`Callback<()>`). Default: No-op.
+/// - **on_error**: Callback triggered when the scroll cannot be carried out (`Callback<ScrollError>`). Default: No-op.
+/// - **update_hash**: How the URL is updated: `None`, `Push`, `Replace`, `Query` or `Custom` (`Signal<HashMode, LocalStorage>`). Default: `Push`.
+/// - **show_id**: ID of a container controlling the button's visibility (`Signal<String>`). Default: `""`.
+/// - **aria_label**: Accessible name of the button (`MaybeProp<String>`). Default: a description of the target.
+/// - **title**: Tooltip of the button (`MaybeProp<String>`). Default: the accessible name.
+/// - **focus_target**: Whether to focus an element target once the scroll has finished (`Signal<bool>`). Default: `true`.
+/// - **reduced_motion**: How `prefers-reduced-motion` is honoured: `System`, `Always` or `Never` (`Signal<ReducedMotion>`). Default: `System`.
 ///
 /// # Features
 /// - Automatically hides or shows based on scroll position.
-/// - Smooth or instant scrolling to specific positions.
+/// - Renders a native `<button type="button">`, so it is focusable, labelled and activated with Enter or Space.
+/// - Smooth, instant or custom-eased animated scrolling to specific positions.
 /// - Customizable content, including icons or HTML.
 /// - Configurable callbacks for when scrolling begins and ends.
 /// - Adjustable offsets and delays for precise control.
 ///
 /// # Behavior
-/// - The component listens to the window's scroll events to manage its visibility based on the `threshold`
-///   or `show_id` container.
+/// - The component listens to the `container`'s scroll events to manage its visibility based on the `threshold`
+///   or `show_id` element.
 /// - Clicking the button triggers the scroll action, applying offsets, delays, and the `behavior`.
 /// - Optional callbacks (`on_begin`, `on_end`) allow integration with custom logic during scroll events.
 ///
 /// # Notes
-/// - Ensure `scroll_id` and `show_id` refer to valid element IDs in your DOM.
+/// - Ensure element targets and `show_id` refer to elements in your DOM.
+/// - To render your own trigger, such as a design-system button, use [`HeadlessScroll`], which runs the same logic
+///   without any markup.
 /// - Customize the `style` and `icon` properties to fit your application's design.
-/// - The `update_hash` property is useful for SEO and navigation but can be disabled if not needed.
+/// - The `update_hash` property is useful for SEO and navigation; use `HashMode::Replace` to keep the back stack clean, or
+///   `HashMode::None` to disable it.
+/// - Safe to render on the server. The first render only depends on the props: the button starts hidden when
+///   `auto_hide` is on and shown otherwise, and the DOM is only touched from effects and event handlers, so hydration
+///   never mismatches.
 #[component]
 pub fn Scroll(
     /// Custom inline styles for the scroll button.
     ///
-    /// Accepts a `&'static str` to define CSS properties for the button.
+    /// Accepts a string or any `Signal<String>`, so styles can change reactively.
     /// Defaults to a built-in `SCROLL_TO_TOP_STYLE`.
-    #[prop(default = SCROLL_TO_TOP_STYLE)]
-    style: &'static str,
+    #[prop(default = SCROLL_TO_TOP_STYLE.into(), into)]
+    style: Signal<String>,
 
     /// Custom CSS classes for the scroll button.
     ///
-    /// Accepts a `&'static str` for adding additional styles via class selectors.
+    /// Accepts a string or any `Signal<String>` for adding additional styles via class selectors.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    class: &'static str,
+    #[prop(optional, into)]
+    class: Signal<String>,
 
     /// Custom icon for the scroll button.
     ///
-    /// This can be a string, such as `"↑"`, or any HTML/SVG representation of the icon.
-    /// Defaults to `"↑"`.
-    /// TODO: Change type to View<>
-    #[prop(default = "↑")]
-    icon: &'static str,
+    /// Accepts any closure returning a view, such as `|| "↑"` or an inline SVG. It is called again
+    /// whenever the button reappears. Defaults to an internal SVG icon.
+    #[prop(default = default_svg.into(), into)]
+    icon: ViewFn,
 
     /// Behavior of the scroll action.
     ///
     /// Determines how scrolling occurs. Can be `Behavior::Smooth` or `Behavior::Instant`.
     /// Defaults to `Behavior::Smooth`.
-    #[prop(default = Behavior::Smooth)]
-    behavior: Behavior,
+    #[prop(default = Behavior::Smooth.into(), into)]
+    behavior: Signal<Behavior, LocalStorage>,
 
-    /// Vertical scroll target position in pixels.
+    /// Target of the scroll action.
     ///
-    /// Specifies the `top` position to scroll to. Defaults to `0.0`.
-    #[prop(default = 0.0)]
-    top: f64,
+    /// Can be an absolute position, an element ID, a CSS selector, a `NodeRef`, the page bottom,
+    /// a percentage of the page or a relative delta. Pass a signal to change the target reactively.
+    /// Defaults to the top of the page.
+    #[prop(optional, into)]
+    target: Signal<ScrollTarget, LocalStorage>,
 
-    /// Horizontal scroll target position in pixels.
+    /// Scrollable area the button drives and watches.
     ///
-    /// Specifies the `left` position to scroll to (useful for horizontal scrolling).
-    /// Defaults to `0.0`.
-    #[prop(default = 0.0)]
-    left: f64,
+    /// Can be the window, or an element (by ID, CSS selector or `NodeRef`) with its own overflow
+    /// scrolling, such as a sidebar or a modal body. Both the scroll action and the auto-hide logic
+    /// use this container. Defaults to the window.
+    #[prop(optional, into)]
+    container: Signal<ScrollContainer, LocalStorage>,
 
     /// Additional offset in pixels for the scroll target.
     ///
     /// Useful for adjusting the scroll position to account for fixed headers or other elements.
     /// Defaults to `0.0`.
-    #[prop(default = 0.0)]
-    offset: f64,
+    #[prop(optional, into)]
+    offset: Signal<f64>,
 
     /// Delay before initiating the scroll action, in milliseconds.
     ///
     /// Introduces a delay before scrolling begins. Defaults to `0`.
-    #[prop(default = 0)]
-    delay: u64,
+    #[prop(optional, into)]
+    delay: Signal<u64>,
 
     /// Enable or disable automatic visibility based on scroll position.
     ///
     /// When `true`, the button will appear or hide automatically depending on the user's scroll position.
     /// Defaults to `true`.
-    #[prop(default = true)]
-    auto_hide: bool,
+    #[prop(default = true.into(), into)]
+    auto_hide: Signal<bool>,
 
     /// Scroll threshold in pixels for button visibility.
     ///
     /// Defines how far down the page a user needs to scroll before the button appears.
     /// Defaults to `20.0`.
-    #[prop(default = 20.0)]
-    threshold: f64,
+    #[prop(default = 20.0.into(), into)]
+    threshold: Signal<f64>,
 
     /// Callback triggered when scrolling begins.
     ///
@@ -129,134 +208,132 @@ pub fn Scroll(
     #[prop(default = Callback::from(move || {}))]
     on_begin: Callback<()>,
 
-    /// Callback triggered when scrolling ends.
+    /// Callback triggered once the scroll has actually finished.
+    ///
+    /// For smooth and animated scrolls this is when the destination is reached, not when the scroll
+    /// is issued, so it is safe to focus or highlight the target here.
     ///
     /// Use this callback to handle actions like resetting states or displaying notifications when the scroll completes.
     #[prop(default = Callback::from(move || {}))]
     on_end: Callback<()>,
 
-    /// Update the URL hash during scrolling.
+    /// Callback triggered when a scroll is cancelled before it finishes.
     ///
-    /// When `true`, the browser's URL hash will reflect the scroll target. Defaults to `true`.
-    #[prop(default = true)]
-    update_hash: bool,
+    /// Wheel, touch or keyboard scrolling by the user, a second click on the button, or unmounting
+    /// the component cancels a pending or running scroll. `on_end` is not triggered for cancelled
+    /// scrolls.
+    #[prop(default = Callback::from(move || {}))]
+    on_cancel: Callback<()>,
+
+    /// Callback triggered when a scroll cannot be carried out.
+    ///
+    /// Receives a `ScrollError` when the container or target element cannot be found, a selector
+    /// is invalid, or the browser refuses the URL update. Neither `on_end` nor `on_cancel` is
+    /// triggered for scrolls that never start.
+    #[prop(default = Callback::new(|_| {}))]
+    on_error: Callback<ScrollError>,
+
+    /// How the URL is updated when scrolling to an element with an ID.
+    ///
+    /// `HashMode::Push` adds a history entry, `HashMode::Replace` rewrites the current one,
+    /// `HashMode::Query` sets a query parameter and `HashMode::Custom` hands the ID to a callback.
+    /// Use `HashMode::None` to leave the URL alone, and `HashMode::from` to turn a former `bool`
+    /// into a mode. Defaults to `HashMode::Push`.
+    #[prop(default = HashMode::Push.into(), into)]
+    update_hash: Signal<HashMode, LocalStorage>,
 
     /// Target container ID for displaying the scroll button.
     ///
     /// Specifies the ID of a container where the button's visibility is based on the container's scroll position.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    show_id: &'static str,
+    /// Can be computed reactively, e.g. per list item. Defaults to an empty string.
+    #[prop(optional, into)]
+    show_id: Signal<String>,
 
-    /// Target container ID for scrolling actions.
+    /// Accessible name of the button, read out by screen readers.
     ///
-    /// Specifies the ID of the container to which scrolling actions should apply.
-    /// Defaults to an empty string.
-    #[prop(default = "")]
-    scroll_id: &'static str,
-) -> impl IntoView {
-    let (visible, set_visible) = signal(false);
-    let (behavior, _set_behavior) = signal(behavior);
-
-    let scroll_handler = move || {
-        if let Some(container) = window().document().unwrap().get_element_by_id(show_id) {
-            let scroll_position = window().scroll_y().unwrap_or(0.0);
-            let container_position = container.get_bounding_client_rect().top();
-            set_visible.set(scroll_position > container_position);
-        } else {
-            let scroll_position = window().scroll_y().unwrap_or(0.0);
-            set_visible.set(scroll_position > threshold);
-        }
-    };
+    /// Defaults to a description of the target, such as "Scroll to top" or "Scroll to comments".
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
 
-    if auto_hide {
-        window_event_listener(scroll, move |_ev| scroll_handler());
-    }
+    /// Tooltip shown when hovering the button.
+    ///
+    /// Defaults to the accessible name.
+    #[prop(optional, into)]
+    title: MaybeProp<String>,
 
-    let on_click = {
-        move |_: MouseEvent| {
-            if delay > 0 {
-                let delay = Duration::from_millis(delay);
-                set_timeout(
-                    move || {
-                        on_begin.run(());
-                        perform_scroll(
-                            top,
-                            left,
-                            offset,
-                            behavior.get(),
-                            update_hash,
-                            scroll_id.to_string(),
-                        );
-                        on_end.run(());
-                    },
-                    delay,
-                );
-            } else {
-                on_begin.run(());
-                perform_scroll(
-                    top,
-                    left,
-                    offset,
-                    behavior.get(),
-                    update_hash,
-                    scroll_id.to_string(),
-                );
-                on_end.run(());
-            }
-        }
-    };
+    /// Whether to move keyboard focus to an element target once the scroll has finished.
+    ///
+    /// Elements that are not focusable on their own get `tabindex="-1"`, and focusing never scrolls the
+    /// page again. Keyboard and screen-reader users then continue from where the page scrolled to.
+    /// Defaults to `true`.
+    #[prop(default = true.into(), into)]
+    focus_target: Signal<bool>,
 
+    /// How the button honours the user's `prefers-reduced-motion` setting.
+    ///
+    /// With `ReducedMotion::System`, smooth and animated scrolls jump straight to the target while the
+    /// operating system asks for reduced motion. Use `ReducedMotion::Never` to keep `behavior` regardless,
+    /// or `ReducedMotion::Always` to never animate. Defaults to `ReducedMotion::System`.
+    #[prop(optional, into)]
+    reduced_motion: Signal<ReducedMotion>,
+) -> impl IntoView {
     view! {
-        {move ||
-            if visible.get() {
-                Some(view! {
-                    <div
-                        class={class}
-                        style={style}
-                        on:click={on_click}
-                    >
-                        {icon}
-                    </div>
+        <HeadlessScroll
+            behavior=behavior
+            target=target
+            container=container
+            offset=offset
+            delay=delay
+            auto_hide=auto_hide
+            threshold=threshold
+            on_begin=on_begin
+            on_end=on_end
+            on_cancel=on_cancel
+            on_error=on_error
+            update_hash=update_hash
+            show_id=show_id
+            aria_label=aria_label
+            focus_target=focus_target
+            reduced_motion=reduced_motion
+            let:trigger
+        >
+            {
+                let icon = icon.clone();
+                move || trigger.visible.get().then(|| {
+                    view! {
+                        <button
+                            type="button"
+                            class={class}
+                            style={style}
+                            aria-label=move || trigger.aria.get().label
+                            title=move || title.get().unwrap_or_else(|| trigger.aria.get().label)
+                            on:click=move |_| trigger.scroll.run(())
+                        >
+                            {icon.run()}
+                        </button>
+                    }
                 })
-            } else {
-                None
             }
-        }
+        </HeadlessScroll>
     }
 }
 
-fn perform_scroll(
-    top: f64,
-    left: f64,
-    offset: f64,
-    behavior: Behavior,
-    update_hash: bool,
-    scroll_id: String,
-) {
-    let options = ScrollToOptions::new();
-    options.set_left(left);
-    match behavior {
-        Behavior::Auto => options.set_behavior(ScrollBehavior::Auto),
-        Behavior::Instant => options.set_behavior(ScrollBehavior::Instant),
-        Behavior::Smooth => options.set_behavior(ScrollBehavior::Smooth),
-    }
-
-    if let Some(container) = window().document().unwrap().get_element_by_id(&scroll_id) {
-        let container_position = container.get_bounding_client_rect().top();
-        options.set_top(container_position + offset);
-    } else {
-        options.set_top(top + offset);
-    }
-
-    window().scroll_with_scroll_to_options(&options);
-
-    if update_hash {
-        let hash = format!("#{}", scroll_id);
-        window()
-            .history()
-            .unwrap()
-            .push_state_with_url(&JsValue::NULL, "", Some(&hash))
-            .unwrap();
+/// Default SVG icon for the scroll button.
+fn default_svg() -> impl IntoView {
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            fill="none"
+            viewBox="0 0 24 24"
+            stroke="currentColor"
+            style="width: 16px; height: 16px;"
+        >
+            <path
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                stroke-width="2"
+                d="M5 10l7-7m0 0l7 7m-7-7v18"
+            />
+        </svg>
     }
 }
@@ -0,0 +1,42 @@
+use crate::common::{ScrollController, ScrollOptions};
+use leptos::prelude::*;
+
+/// Scrolls from your own code, e.g. after a form submit or on a keyboard shortcut.
+///
+/// Returns a [`ScrollController`] with `scroll_to`, `scroll_by`, `scroll_into_view`,
+/// `scroll_to_top`, `scroll_to_bottom` and `stop`. It scrolls exactly like the `Scroll` component,
+/// including its `on_begin`, `on_end`, `on_cancel` and `on_error` callbacks, which can be set on the
+/// controller. A running scroll is cancelled when the owner is cleaned up.
+///
+/// The controller is not `Send`; wrap it in `StoredValue::new_local` to use it inside reactive
+/// closures.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use scroll_rs::leptos::use_scroll;
+/// use scroll_rs::{ScrollOptions, ScrollTarget};
+///
+/// #[component]
+/// pub fn Signup() -> impl IntoView {
+///     let scroll = use_scroll(ScrollOptions { offset: -64.0, ..Default::default() });
+///     let on_submit = move |event: leptos::ev::SubmitEvent| {
+///         event.prevent_default();
+///         scroll.scroll_to(ScrollTarget::id("confirmation"));
+///     };
+///     view! { <form on:submit=on_submit><button>"Sign up"</button></form> }
+/// }
+/// ```
+pub fn use_scroll(options: ScrollOptions) -> ScrollController {
+    let controller = ScrollController::new(options);
+    let stored = StoredValue::new_local(controller.clone());
+
+    on_cleanup(move || {
+        if let Some(controller) = stored.try_get_value() {
+            controller.stop();
+        }
+    });
+
+    controller
+}
@@ -0,0 +1,28 @@
+use crate::common::{HashScroll, HashScrollOptions};
+use crate::leptos::use_owned_effect;
+use leptos::prelude::*;
+
+/// Scrolls to the element named by the URL hash on mount and on every `hashchange`.
+///
+/// Shared links such as `/docs#install` then land on the section with the configured `offset` and
+/// `behavior` applied, even when the section is rendered after the page loads. The listener is
+/// removed when the component is cleaned up.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use scroll_rs::leptos::use_scroll_to_hash;
+/// use scroll_rs::HashScrollOptions;
+///
+/// #[component]
+/// pub fn Docs() -> impl IntoView {
+///     use_scroll_to_hash(HashScrollOptions { offset: -64.0, ..Default::default() });
+///     view! { <section id="install">"Install"</section> }
+/// }
+/// ```
+pub fn use_scroll_to_hash(options: HashScrollOptions) {
+    let options = StoredValue::new_local(options);
+
+    use_owned_effect(move || HashScroll::start(options.get_value()));
+}
@@ -0,0 +1,212 @@
+use crate::common::{
+    Behavior, HashMode, ReducedMotion, ScrollAria, ScrollContainer, ScrollEngine, ScrollError,
+    ScrollOptions, ScrollTarget,
+};
+use crate::leptos::{use_owned_effect, use_scroll};
+use leptos::prelude::*;
+
+/// State handed to the children of [`HeadlessScroll`].
+#[derive(Clone, Copy)]
+pub struct ScrollTrigger {
+    /// Whether the trigger should be shown, following `auto_hide`, `threshold`
+    /// and `show_id`.
+    pub visible: Signal<bool>,
+    /// How far the container has been scrolled through, from `0.0` to `1.0`.
+    pub progress: Signal<f64>,
+    /// Whether a scroll is pending or running.
+    pub is_scrolling: Signal<bool>,
+    /// Starts the scroll, or cancels the one in progress, exactly like a click
+    /// on the `Scroll` button.
+    pub scroll: Callback<()>,
+    /// ARIA attributes describing the trigger.
+    pub aria: Signal<ScrollAria>,
+}
+
+/// HeadlessScroll Component
+///
+/// The logic of [`Scroll`](crate::leptos::Scroll) without any markup: it tracks visibility, progress and
+/// the running scroll, and hands them to its children together with a `scroll` action and ARIA attributes.
+/// Use it to turn a button from your own design system into a scroll trigger.
+///
+/// The props are the ones of `Scroll` that control visibility and scrolling; everything visual is left to
+/// the children, which receive a [`ScrollTrigger`] through `let:`.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use scroll_rs::leptos::HeadlessScroll;
+///
+/// #[component]
+/// pub fn BackToTop() -> impl IntoView {
+///     view! {
+///         <HeadlessScroll let:trigger>
+///             <button
+///                 class="fab"
+///                 class:fab-visible=trigger.visible
+///                 aria-label=move || trigger.aria.get().label
+///                 aria-hidden=move || trigger.aria.get().hidden.to_string()
+///                 aria-busy=move || trigger.aria.get().busy.to_string()
+///                 on:click=move |_| trigger.scroll.run(())
+///             >
+///                 {move || format!("{:.0}%", trigger.progress.get() * 100.0)}
+///             </button>
+///         </HeadlessScroll>
+///     }
+/// }
+/// ```
+#[component]
+pub fn HeadlessScroll<F, IV>(
+    /// Renders the trigger from its [`ScrollTrigger`] state.
+    children: F,
+
+    /// Behavior of the scroll action. Defaults to `Behavior::Smooth`.
+    #[prop(default = Behavior::Smooth.into(), into)]
+    behavior: Signal<Behavior, LocalStorage>,
+
+    /// Target of the scroll action. Defaults to the top of the page.
+    #[prop(optional, into)]
+    target: Signal<ScrollTarget, LocalStorage>,
+
+    /// Scrollable area the trigger drives and watches. Defaults to the window.
+    #[prop(optional, into)]
+    container: Signal<ScrollContainer, LocalStorage>,
+
+    /// Additional offset in pixels for the scroll target. Defaults to `0.0`.
+    #[prop(optional, into)]
+    offset: Signal<f64>,
+
+    /// Delay before initiating the scroll action, in milliseconds. Defaults to `0`.
+    #[prop(optional, into)]
+    delay: Signal<u64>,
+
+    /// Whether `visible` follows the scroll position. Defaults to `true`.
+    #[prop(default = true.into(), into)]
+    auto_hide: Signal<bool>,
+
+    /// Scroll position in pixels after which the trigger becomes visible. Defaults to `20.0`.
+    #[prop(default = 20.0.into(), into)]
+    threshold: Signal<f64>,
+
+    /// Callback triggered when scrolling begins.
+    #[prop(default = Callback::from(move || {}))]
+    on_begin: Callback<()>,
+
+    /// Callback triggered once the scroll has actually finished.
+    #[prop(default = Callback::from(move || {}))]
+    on_end: Callback<()>,
+
+    /// Callback triggered when a scroll is cancelled before it finishes.
+    #[prop(default = Callback::from(move || {}))]
+    on_cancel: Callback<()>,
+
+    /// Callback triggered when a scroll cannot be carried out.
+    #[prop(default = Callback::new(|_| {}))]
+    on_error: Callback<ScrollError>,
+
+    /// How the URL is updated when scrolling to an element with an ID. Defaults to `HashMode::Push`.
+    #[prop(default = HashMode::Push.into(), into)]
+    update_hash: Signal<HashMode, LocalStorage>,
+
+    /// ID of a container whose scroll position controls visibility. Defaults to an empty string.
+    #[prop(optional, into)]
+    show_id: Signal<String>,
+
+    /// Accessible name of the trigger, handed out as `aria.label`. Defaults to a description of the
+    /// target, such as "Scroll to top".
+    #[prop(optional, into)]
+    aria_label: MaybeProp<String>,
+
+    /// Whether to move keyboard focus to element targets once the scroll has finished. Defaults to `true`.
+    #[prop(default = true.into(), into)]
+    focus_target: Signal<bool>,
+
+    /// Whether smooth and animated scrolls become instant when the user prefers reduced motion.
+    /// Defaults to `ReducedMotion::System`.
+    #[prop(optional, into)]
+    reduced_motion: Signal<ReducedMotion>,
+) -> impl IntoView
+where
+    F: Fn(ScrollTrigger) -> IV + 'static,
+    IV: IntoView + 'static,
+{
+    let (visible, set_visible) = signal(!auto_hide.get_untracked());
+    let (progress, set_progress) = signal(0.0);
+    let (is_scrolling, set_is_scrolling) = signal(false);
+
+    use_owned_effect(move || {
+        let (auto_hide, threshold, show_id) = (auto_hide.get(), threshold.get(), show_id.get());
+        if !auto_hide && !visible.get_untracked() {
+            set_visible.set(true);
+        }
+        let engine = container.with(ScrollEngine::with_container).ok()?;
+        let update = move |engine: &ScrollEngine| {
+            // Only write on change, so scrolling does not notify subscribers on
+            // every frame.
+            let is_visible = !auto_hide || engine.is_past(threshold, &show_id);
+            if visible.get_untracked() != is_visible {
+                set_visible.set(is_visible);
+            }
+            let value = engine.progress(None);
+            if progress.get_untracked() != value {
+                set_progress.set(value);
+            }
+        };
+        update(&engine);
+        let root = engine.root().clone();
+        Some(root.on_scroll(move || update(&engine)))
+    });
+
+    let controller = use_scroll(ScrollOptions::default());
+    controller
+        .on_begin(move || on_begin.run(()))
+        .on_end(move || {
+            set_is_scrolling.set(false);
+            on_end.run(());
+        })
+        .on_cancel(move || {
+            set_is_scrolling.set(false);
+            on_cancel.run(());
+        })
+        .on_error(move |error| {
+            set_is_scrolling.set(false);
+            on_error.run(error);
+        });
+    let controller = StoredValue::new_local(controller);
+
+    let scroll = Callback::new(move |()| {
+        // A second call while a scroll is pending or running cancels it.
+        let controller = controller.get_value();
+        if controller.stop() {
+            return;
+        }
+        controller.set_options(ScrollOptions {
+            container: container.get_untracked(),
+            offset: offset.get_untracked(),
+            behavior: behavior.get_untracked(),
+            update_hash: update_hash.get_untracked(),
+            delay_ms: u32::try_from(delay.get_untracked()).unwrap_or(u32::MAX),
+            focus_target: focus_target.get_untracked(),
+            reduced_motion: reduced_motion.get_untracked(),
+        });
+        let task = controller.scroll_to(target.get_untracked());
+        set_is_scrolling.set(task.is_running());
+    });
+
+    let aria = Signal::derive(move || {
+        let mut aria =
+            target.with(|target| ScrollAria::new(target, visible.get(), is_scrolling.get()));
+        if let Some(label) = aria_label.get() {
+            aria.label = label;
+        }
+        aria
+    });
+
+    children(ScrollTrigger {
+        visible: visible.into(),
+        progress: progress.into(),
+        is_scrolling: is_scrolling.into(),
+        scroll,
+        aria,
+    })
+}
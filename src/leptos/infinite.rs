@@ -0,0 +1,112 @@
+use crate::common::{ScrollContainer, SentinelObserver, SENTINEL_ROOT_MARGIN};
+use crate::leptos::use_owned_effect;
+use leptos::html::Div;
+use leptos::prelude::*;
+
+/// InfiniteScroll Component
+///
+/// A Leptos component for feeds that load page by page. It renders its children followed by a
+/// sentinel element watched by an `IntersectionObserver`, and runs `on_reach_end` when the
+/// sentinel enters the visible area of the window or the configured container. While `loading` is
+/// `true` or `has_more` is `false` the sentinel is not observed, so each page is requested once.
+/// If the list is still too short to fill the container after a page loads, the next page is
+/// requested right away.
+///
+/// # Properties
+///
+/// - **on_reach_end**: Callback triggered when the end of the list comes into reach (`Callback<()>`).
+/// - **loading**: Whether a page is currently being fetched (`Signal<bool>`). Default: `false`.
+/// - **has_more**: Whether there is more content to load (`Signal<bool>`). Default: `true`.
+/// - **root_margin**: `IntersectionObserver` root margin (`String`). Default: `"0px 0px 200px 0px"`.
+/// - **container**: Scrollable area the list lives in (`ScrollContainer`). Default: the window.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use leptos::task::spawn_local;
+/// use scroll_rs::leptos::InfiniteScroll;
+///
+/// #[component]
+/// pub fn Feed() -> impl IntoView {
+///     let (items, set_items) = signal((0..20).collect::<Vec<u32>>());
+///     let (loading, set_loading) = signal(false);
+///
+///     let on_reach_end = move || {
+///         set_loading.set(true);
+///         spawn_local(async move {
+///             let page = fetch_page(items.get_untracked().len()).await;
+///             set_items.update(|items| items.extend(page));
+///             set_loading.set(false);
+///         });
+///     };
+///
+///     view! {
+///         <InfiniteScroll
+///             on_reach_end=on_reach_end
+///             loading=loading
+///             has_more=Signal::derive(move || items.get().len() < 200)
+///         >
+///             <For each=move || items.get() key=|item| *item let:item>
+///                 <p>{item}</p>
+///             </For>
+///         </InfiniteScroll>
+///     }
+/// }
+/// # async fn fetch_page(from: usize) -> Vec<u32> { (from as u32..from as u32 + 20).collect() }
+/// ```
+#[component]
+pub fn InfiniteScroll(
+    /// Callback triggered when the end of the list comes into reach.
+    ///
+    /// Not triggered while `loading` is `true` or once `has_more` is `false`.
+    #[prop(into)]
+    on_reach_end: Callback<()>,
+
+    /// Whether a page is currently being fetched.
+    ///
+    /// Set this to `true` from `on_reach_end` and back to `false` once the page has been appended
+    /// to prevent duplicate requests. Defaults to `false`.
+    #[prop(optional, into)]
+    loading: Signal<bool>,
+
+    /// Whether there is more content to load.
+    ///
+    /// Defaults to `true`.
+    #[prop(default = Signal::stored(true), into)]
+    has_more: Signal<bool>,
+
+    /// `IntersectionObserver` root margin, in CSS shorthand.
+    ///
+    /// Defaults to `"0px 0px 200px 0px"`, which fires 200px before the end of the list becomes
+    /// visible.
+    #[prop(default = SENTINEL_ROOT_MARGIN.to_string(), into)]
+    root_margin: String,
+
+    /// Scrollable area the list lives in.
+    ///
+    /// Defaults to the window.
+    #[prop(default = ScrollContainer::default(), into)]
+    container: ScrollContainer,
+
+    /// The items loaded so far.
+    children: Children,
+) -> impl IntoView {
+    let sentinel = NodeRef::<Div>::new();
+    let container = StoredValue::new_local(container);
+
+    use_owned_effect(move || {
+        let element = sentinel.get()?;
+        if loading.get() || !has_more.get() {
+            return None;
+        }
+        SentinelObserver::observe(&element, &container.get_value(), &root_margin, move || {
+            on_reach_end.run(())
+        })
+    });
+
+    view! {
+        {children()}
+        <div node_ref=sentinel aria-hidden="true" style="height: 1px;"></div>
+    }
+}
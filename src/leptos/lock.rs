@@ -0,0 +1,43 @@
+use crate::common::{ScrollLock, ScrollLockOptions};
+use crate::leptos::use_owned_effect;
+use leptos::prelude::*;
+
+/// Locks the page scroll while `locked` is `true`.
+///
+/// Meant for modals and drawers: the page behind them stops scrolling while the elements in
+/// `options.allow` keep scrolling. Nested locks are reference counted, so the page is only
+/// unlocked once every component holding a lock has released it. The lock is also released when
+/// the component is cleaned up.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use scroll_rs::leptos::use_scroll_lock;
+/// use scroll_rs::{ScrollContainer, ScrollLockOptions};
+///
+/// #[component]
+/// pub fn Modal(#[prop(into)] open: Signal<bool>, children: ChildrenFn) -> impl IntoView {
+///     use_scroll_lock(
+///         open,
+///         ScrollLockOptions {
+///             allow: vec![ScrollContainer::id("modal-body")],
+///             ..Default::default()
+///         },
+///     );
+///     view! {
+///         <Show when=move || open.get()>
+///             <div class="modal"><div id="modal-body">{children()}</div></div>
+///         </Show>
+///     }
+/// }
+/// ```
+pub fn use_scroll_lock(locked: Signal<bool>, options: ScrollLockOptions) {
+    let options = StoredValue::new_local(options);
+
+    use_owned_effect(move || {
+        locked
+            .get()
+            .then(|| ScrollLock::acquire(&options.get_value()))
+    });
+}
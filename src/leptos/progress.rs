@@ -0,0 +1,103 @@
+use crate::common::{ScrollContainer, ScrollEngine, ScrollTarget, SCROLL_PROGRESS_STYLE};
+use crate::leptos::use_owned_effect;
+use leptos::prelude::*;
+
+/// ScrollProgress Component
+///
+/// A Leptos component that renders a reading-progress bar. The bar fills from `0%` to `100%` as
+/// the window, a scrollable container or a single element is scrolled through. Use
+/// [`use_scroll_progress`] instead to render the progress in any other way.
+///
+/// # Properties
+///
+/// - **style**: Inline styles for the bar; its width is appended (`Signal<String>`). Default: `SCROLL_PROGRESS_STYLE`.
+/// - **class**: CSS classes for the bar (`Signal<String>`). Default: `""`.
+/// - **container**: Scrollable area whose progress is measured (`ScrollContainer`). Default: the window.
+/// - **element**: Element to measure progress through (`Option<ScrollTarget>`). Default: `None`.
+/// - **on_change**: Callback with the new progress whenever it changes (`Callback<f64>`). Default: No-op.
+#[component]
+pub fn ScrollProgress(
+    /// Custom inline styles for the progress bar.
+    ///
+    /// Accepts a string or any `Signal<String>`, so styles can change reactively. The bar's `width`
+    /// is appended to these styles on every update.
+    #[prop(default = SCROLL_PROGRESS_STYLE.into(), into)]
+    style: Signal<String>,
+
+    /// Custom CSS classes for the progress bar.
+    ///
+    /// Accepts a string or any `Signal<String>`.
+    #[prop(optional, into)]
+    class: Signal<String>,
+
+    /// Scrollable area whose progress is measured.
+    ///
+    /// Defaults to the window.
+    #[prop(default = ScrollContainer::default(), into)]
+    container: ScrollContainer,
+
+    /// Element to measure progress through, such as an article.
+    ///
+    /// Progress starts when the element's top reaches the top of the container and completes when
+    /// its bottom comes into view. Defaults to `None`, which measures the whole container.
+    #[prop(optional, into)]
+    element: Option<ScrollTarget>,
+
+    /// Callback triggered with the new progress (`0.0..=1.0`) whenever it changes.
+    #[prop(default = Callback::new(move |_| {}))]
+    on_change: Callback<f64>,
+) -> impl IntoView {
+    let progress = use_progress(container, element, Some(on_change));
+    let percent = move || progress.get() * 100.0;
+
+    view! {
+        <div
+            class={class}
+            style={move || format!("{} width: {}%;", style.get(), percent())}
+            role="progressbar"
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow={move || percent().round().to_string()}
+        />
+    }
+}
+
+/// Tracks how far `container`, or `element` within it, has been scrolled through.
+///
+/// Returns a signal holding the progress from `0.0` to `1.0`. The listener is attached once the
+/// component is mounted and removed when it is cleaned up.
+pub fn use_scroll_progress(
+    container: ScrollContainer,
+    element: Option<ScrollTarget>,
+) -> ReadSignal<f64> {
+    use_progress(container, element, None)
+}
+
+fn use_progress(
+    container: ScrollContainer,
+    element: Option<ScrollTarget>,
+    on_change: Option<Callback<f64>>,
+) -> ReadSignal<f64> {
+    let (progress, set_progress) = signal(0.0);
+    let container = StoredValue::new_local(container);
+    let element = StoredValue::new_local(element);
+
+    use_owned_effect(move || {
+        let engine = ScrollEngine::with_container(&container.get_value()).ok()?;
+        let element = element.get_value();
+        set_progress.set(engine.progress(element.as_ref()));
+        let root = engine.root().clone();
+        Some(root.on_scroll(move || {
+            let value = engine.progress(element.as_ref());
+            if progress.get_untracked() == value {
+                return;
+            }
+            set_progress.set(value);
+            if let Some(on_change) = on_change {
+                on_change.run(value);
+            }
+        }))
+    });
+
+    progress
+}
@@ -0,0 +1,43 @@
+use crate::common::{ScrollRestoration, ScrollRestorationOptions};
+use crate::leptos::use_owned_effect;
+use leptos::prelude::*;
+use std::rc::{Rc, Weak};
+
+/// Saves the scroll position of every history entry and restores it on back/forward navigation.
+///
+/// Pass a signal that identifies the current route, such as the path from `leptos_router`'s
+/// `use_location`, and call the hook once near the root of the app. Fresh navigations scroll to
+/// the URL hash target, or to the top when there is none. The saved positions live as long as
+/// the calling component.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use scroll_rs::leptos::use_scroll_restoration;
+/// use scroll_rs::ScrollRestorationOptions;
+///
+/// #[component]
+/// pub fn Layout(#[prop(into)] path: Signal<String>, children: Children) -> impl IntoView {
+///     use_scroll_restoration(path, ScrollRestorationOptions::default());
+///     children()
+/// }
+/// ```
+pub fn use_scroll_restoration(route: Signal<String>, options: ScrollRestorationOptions) {
+    let options = StoredValue::new_local(options);
+    let current = StoredValue::new_local(Weak::<ScrollRestoration>::new());
+
+    use_owned_effect(move || {
+        let restoration = Rc::new(ScrollRestoration::new(options.get_value())?);
+        restoration.navigate(&route.get_untracked());
+        current.set_value(Rc::downgrade(&restoration));
+        Some(restoration)
+    });
+
+    Effect::new(move |_| {
+        let route = route.get();
+        if let Some(restoration) = current.with_value(Weak::upgrade) {
+            restoration.navigate(&route);
+        }
+    });
+}
@@ -0,0 +1,93 @@
+use crate::common::{ScrollContainer, ScrollSpyObserver, ScrollSpyOptions};
+use crate::leptos::use_owned_effect;
+use leptos::prelude::*;
+
+/// ScrollSpy Component
+///
+/// A Leptos component that tracks which section of a long page is currently in view using an
+/// `IntersectionObserver`, and runs `on_change` with the active section ID. It renders its
+/// children unchanged, so it can wrap a navigation menu or sit anywhere in the tree.
+///
+/// # Properties
+///
+/// - **ids**: IDs of the sections to track, in document order (`Vec<String>`).
+/// - **offset**: Offset added to the top of the observed area, e.g. `-64.0` for a fixed header (`f64`). Default: `0.0`.
+/// - **root_margin**: `IntersectionObserver` root margin (`String`). Default: `"0px 0px -50% 0px"`.
+/// - **threshold**: Fraction of a section that must be visible (`f64`). Default: `0.0`.
+/// - **container**: Scrollable area the sections live in (`ScrollContainer`). Default: the window.
+/// - **on_change**: Callback with the new active section ID (`Callback<Option<String>>`). Default: No-op.
+#[component]
+pub fn ScrollSpy(
+    /// IDs of the sections to track, in document order.
+    ids: Vec<String>,
+
+    /// Offset in pixels added to the top of the observed area.
+    ///
+    /// Uses the same convention as `Scroll`'s `offset`: pass the negative height of a fixed header
+    /// so sections hidden behind it are ignored. Defaults to `0.0`.
+    #[prop(default = 0.0)]
+    offset: f64,
+
+    /// `IntersectionObserver` root margin, in CSS shorthand.
+    ///
+    /// Defaults to `"0px 0px -50% 0px"`, i.e. the upper half of the viewport.
+    #[prop(default = ScrollSpyOptions::default().root_margin, into)]
+    root_margin: String,
+
+    /// Fraction of a section that must be visible for it to count as in view.
+    ///
+    /// Defaults to `0.0`.
+    #[prop(default = 0.0)]
+    threshold: f64,
+
+    /// Scrollable area the sections live in.
+    ///
+    /// Defaults to the window.
+    #[prop(default = ScrollContainer::default(), into)]
+    container: ScrollContainer,
+
+    /// Callback triggered with the new active section ID whenever it changes.
+    #[prop(default = Callback::new(move |_| {}))]
+    on_change: Callback<Option<String>>,
+
+    /// Content rendered as-is inside the spy.
+    children: Children,
+) -> impl IntoView {
+    let options = ScrollSpyOptions {
+        offset,
+        root_margin,
+        threshold,
+        container,
+    };
+    use_spy(ids, options, Some(on_change));
+
+    children()
+}
+
+/// Tracks which of the sections with the given `ids` is currently in view.
+///
+/// Returns a signal holding the ID of the active section, or `None` until a section scrolls into
+/// view. The observer is created once the component is mounted and disconnected when it is
+/// cleaned up.
+pub fn use_scroll_spy(ids: Vec<String>, options: ScrollSpyOptions) -> ReadSignal<Option<String>> {
+    use_spy(ids, options, None)
+}
+
+fn use_spy(
+    ids: Vec<String>,
+    options: ScrollSpyOptions,
+    on_change: Option<Callback<Option<String>>>,
+) -> ReadSignal<Option<String>> {
+    let (active, set_active) = signal(None);
+
+    use_owned_effect(move || {
+        ScrollSpyObserver::observe(&ids, &options, move |id| {
+            set_active.set(id.clone());
+            if let Some(on_change) = on_change {
+                on_change.run(id);
+            }
+        })
+    });
+
+    active
+}
@@ -0,0 +1,68 @@
+use crate::common::{ScrollDirection, ScrollState, ScrollStateObserver, ScrollStateOptions};
+use crate::leptos::use_owned_effect;
+use leptos::prelude::*;
+
+/// Tracks the position and movement of the window or a scrollable container.
+///
+/// Returns a signal holding the current [`ScrollState`]: position, direction, velocity and whether
+/// the root is at the top or bottom. The signal is only written when the state changes. The
+/// listener is attached once the component is mounted and removed when it is cleaned up.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use scroll_rs::leptos::use_scroll_state;
+/// use scroll_rs::ScrollStateOptions;
+///
+/// #[component]
+/// pub fn Position() -> impl IntoView {
+///     let state = use_scroll_state(ScrollStateOptions::default());
+///     view! { <p>{move || format!("{:.0}px", state.get().top)}</p> }
+/// }
+/// ```
+pub fn use_scroll_state(options: ScrollStateOptions) -> ReadSignal<ScrollState> {
+    let (state, set_state) = signal(ScrollState::default());
+    use_observer(options, move |next| set_state.set(next));
+    state
+}
+
+/// Tracks which way the user last scrolled.
+///
+/// Returns a signal holding [`ScrollDirection::Idle`] until the root first moves further than the
+/// tolerance. Unlike [`ScrollState::direction`], the last direction is kept once scrolling
+/// settles, which is what a header that hides on the way down and reappears on the way up needs.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use scroll_rs::leptos::use_scroll_direction;
+/// use scroll_rs::{ScrollDirection, ScrollStateOptions};
+///
+/// #[component]
+/// pub fn Header() -> impl IntoView {
+///     let direction = use_scroll_direction(ScrollStateOptions::default());
+///     let style = move || match direction.get() {
+///         ScrollDirection::Down => "transform: translateY(-100%);",
+///         _ => "transform: none;",
+///     };
+///     view! { <header style=style>"My Site"</header> }
+/// }
+/// ```
+pub fn use_scroll_direction(options: ScrollStateOptions) -> ReadSignal<ScrollDirection> {
+    let (direction, set_direction) = signal(ScrollDirection::default());
+    use_observer(options, move |state| {
+        if state.direction != ScrollDirection::Idle && direction.get_untracked() != state.direction
+        {
+            set_direction.set(state.direction);
+        }
+    });
+    direction
+}
+
+fn use_observer(options: ScrollStateOptions, on_change: impl FnMut(ScrollState) + Clone + 'static) {
+    let options = StoredValue::new_local(options);
+
+    use_owned_effect(move || ScrollStateObserver::observe(&options.get_value(), on_change.clone()));
+}
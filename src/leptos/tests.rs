@@ -0,0 +1,24 @@
+use crate::common::testing::{assert_released, live_subscribers, mount_point, settle};
+use crate::leptos::{Scroll, ScrollProgress};
+use leptos::mount::mount_to;
+use leptos::prelude::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+async fn remounting_does_not_accumulate_listeners() {
+    assert_released();
+    for _ in 0..20 {
+        let app = mount_to(mount_point(), || {
+            view! {
+                <Scroll auto_hide=true />
+                <ScrollProgress />
+            }
+        });
+        settle().await;
+        assert_eq!(live_subscribers(), 2);
+
+        drop(app);
+        settle().await;
+        assert_released();
+    }
+}
@@ -16,4 +16,13 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
-pub use common::Behavior;
+pub use common::{
+    Behavior, Easing, HashMode, HashScrollOptions, NodeHandle, ReducedMotion, ScrollAlign,
+    ScrollAria, ScrollContainer, ScrollController, ScrollDirection, ScrollEngine, ScrollError,
+    ScrollLock, ScrollLockOptions, ScrollOptions, ScrollOutcome, ScrollRequest,
+    ScrollRestorationOptions, ScrollSpyOptions, ScrollState, ScrollStateOptions, ScrollTarget,
+    ScrollTask,
+};
+
+#[cfg(all(test, target_arch = "wasm32"))]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
@@ -1,8 +1,31 @@
-use crate::common::{Behavior, SCROLL_TO_TOP_STYLE};
-use gloo::events::EventListener;
-use gloo::utils::window;
-use wasm_bindgen::JsValue;
-use web_sys::{Element, ScrollBehavior, ScrollToOptions};
+mod controller;
+mod hash;
+mod headless;
+mod infinite;
+mod lock;
+mod progress;
+mod restore;
+mod spy;
+mod state;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests;
+
+pub use controller::use_scroll;
+pub use hash::use_scroll_to_hash;
+pub use headless::{HeadlessScroll, HeadlessScrollProps, ScrollTrigger};
+pub use infinite::{InfiniteScroll, InfiniteScrollProps};
+pub use lock::use_scroll_lock;
+pub use progress::{use_scroll_progress, ScrollProgress, ScrollProgressProps};
+pub use restore::use_scroll_restoration;
+pub use spy::{use_scroll_spy, ScrollSpy, ScrollSpyProps};
+pub use state::{use_scroll_direction, use_scroll_state};
+
+use crate::common::{
+    Behavior, HashMode, NodeHandle, ReducedMotion, ScrollContainer, ScrollError, ScrollTarget,
+    SCROLL_TO_TOP_STYLE,
+};
+use wasm_bindgen::JsCast;
+use yew::html::IntoPropValue;
 use yew::prelude::*;
 
 /// Properties for configuring the `Scroll` component.
@@ -14,17 +37,17 @@ use yew::prelude::*;
 pub struct ScrollProps {
     /// Custom inline styles for the scroll-to-top button.
     ///
-    /// Accepts a `&'static str` to define CSS properties for the button.
+    /// Accepts any `AttrValue`, so styles can be computed at runtime.
     /// Defaults to the built-in `SCROLL_TO_TOP_STYLE`.
-    #[prop_or(SCROLL_TO_TOP_STYLE)]
-    pub style: &'static str,
+    #[prop_or(AttrValue::Static(SCROLL_TO_TOP_STYLE))]
+    pub style: AttrValue,
 
     /// Custom CSS classes for the scroll-to-top button.
     ///
-    /// Accepts a `&'static str` for additional styling using class selectors.
+    /// Accepts any `AttrValue` for additional styling using class selectors.
     /// Defaults to an empty string.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
 
     /// Custom icon for the scroll button.
     ///
@@ -40,18 +63,21 @@ pub struct ScrollProps {
     #[prop_or(Behavior::Smooth)]
     pub behavior: Behavior,
 
-    /// Vertical scroll target position in pixels.
+    /// Target of the scroll action.
     ///
-    /// Specifies the top position to scroll to. Defaults to `0.0`.
-    #[prop_or(0.0)]
-    pub top: f64,
+    /// Can be an absolute position, an element ID, a CSS selector, a `NodeRef`,
+    /// the page bottom, a percentage of the page or a relative delta.
+    /// Defaults to the top of the page.
+    #[prop_or_default]
+    pub target: ScrollTarget,
 
-    /// Horizontal scroll target position in pixels.
+    /// Scrollable area the button drives and watches.
     ///
-    /// Specifies the left position to scroll to (for horizontal scrolling).
-    /// Defaults to `0.0`.
-    #[prop_or(0.0)]
-    pub left: f64,
+    /// Can be the window, or an element (by ID, CSS selector or `NodeRef`) with its
+    /// own overflow scrolling, such as a sidebar or a modal body. Both the scroll
+    /// action and the auto-hide logic use this container. Defaults to the window.
+    #[prop_or_default]
+    pub container: ScrollContainer,
 
     /// Additional offset in pixels for the scroll target.
     ///
@@ -87,34 +113,102 @@ pub struct ScrollProps {
     #[prop_or_default]
     pub on_begin: Callback<()>,
 
-    /// Callback triggered when scrolling ends.
+    /// Callback triggered once the scroll has actually finished.
+    ///
+    /// For smooth and animated scrolls this is when the destination is reached,
+    /// not when the scroll is issued, so it is safe to focus or highlight the
+    /// target here.
     ///
     /// Use this to handle actions like resetting states, analytics, or
     /// displaying notifications when the scrolling completes. Defaults to no-op.
     #[prop_or_default]
     pub on_end: Callback<()>,
 
-    /// Update the URL hash during scrolling.
+    /// Callback triggered when a scroll is cancelled before it finishes.
     ///
-    /// When `true`, the browser's URL hash will be updated to reflect the
-    /// scroll target. Defaults to `true`.
-    #[prop_or(true)]
-    pub update_hash: bool,
+    /// Wheel, touch or keyboard scrolling by the user, a second click on the
+    /// button, or unmounting the component cancels a pending or running scroll.
+    /// `on_end` is not triggered for cancelled scrolls. Defaults to no-op.
+    #[prop_or_default]
+    pub on_cancel: Callback<()>,
+
+    /// Callback triggered when a scroll cannot be carried out.
+    ///
+    /// Receives a `ScrollError` when the container or target element cannot be
+    /// found, a selector is invalid, or the browser refuses the URL update.
+    /// Neither `on_end` nor `on_cancel` is triggered for scrolls that never
+    /// start. Defaults to no-op.
+    #[prop_or_default]
+    pub on_error: Callback<ScrollError>,
+
+    /// How the URL is updated when scrolling to an element with an ID.
+    ///
+    /// `HashMode::Push` adds a history entry, `HashMode::Replace` rewrites the
+    /// current one, `HashMode::Query` sets a query parameter and
+    /// `HashMode::Custom` hands the ID to a callback. Use `HashMode::None` (or
+    /// `false`) to leave the URL alone. Defaults to `HashMode::Push`.
+    #[prop_or_default]
+    pub update_hash: HashMode,
 
     /// Target container ID for displaying the scroll button.
     ///
     /// When specified, the button will only be displayed if the scroll
     /// position of the container with the given ID equals to the current scroll y position.
-    /// Defaults to an empty string.
+    /// Can be computed at runtime, e.g. per list item. Defaults to an empty string.
+    #[prop_or_default]
+    pub show_id: AttrValue,
+
+    /// Accessible name of the button, read out by screen readers.
+    ///
+    /// Defaults to a description of the target, such as "Scroll to top" or
+    /// "Scroll to comments".
     #[prop_or_default]
-    pub show_id: &'static str,
+    pub aria_label: Option<AttrValue>,
 
-    /// Target container ID for scrolling actions.
+    /// Tooltip shown when hovering the button.
     ///
-    /// When specified, the scrolling will be applied to the container with
-    /// the given ID, instead of the default scrolling context (e.g. Scrolling to the top). Defaults to an empty string.
+    /// Defaults to the accessible name.
     #[prop_or_default]
-    pub scroll_id: &'static str,
+    pub title: Option<AttrValue>,
+
+    /// Whether to move keyboard focus to an element target once the scroll has finished.
+    ///
+    /// Elements that are not focusable on their own get `tabindex="-1"`, and focusing
+    /// never scrolls the page again. Keyboard and screen-reader users then continue from
+    /// where the page scrolled to. Defaults to `true`.
+    #[prop_or(true)]
+    pub focus_target: bool,
+
+    /// How the button honours the user's `prefers-reduced-motion` setting.
+    ///
+    /// With `ReducedMotion::System`, smooth and animated scrolls jump straight to the
+    /// target while the operating system asks for reduced motion. Use `ReducedMotion::Never`
+    /// to keep `behavior` regardless, or `ReducedMotion::Always` to never animate.
+    /// Defaults to `ReducedMotion::System`.
+    #[prop_or_default]
+    pub reduced_motion: ReducedMotion,
+}
+
+impl From<NodeRef> for ScrollTarget {
+    fn from(node: NodeRef) -> Self {
+        ScrollTarget::Node(node_handle(node))
+    }
+}
+
+impl From<NodeRef> for ScrollContainer {
+    fn from(node: NodeRef) -> Self {
+        ScrollContainer::Node(node_handle(node))
+    }
+}
+
+impl IntoPropValue<HashMode> for bool {
+    fn into_prop_value(self) -> HashMode {
+        self.into()
+    }
+}
+
+fn node_handle(node: NodeRef) -> NodeHandle {
+    NodeHandle::new(move || node.get().and_then(|node| node.dyn_into().ok()))
 }
 
 /// Scroll Component
@@ -127,26 +221,32 @@ pub struct ScrollProps {
 /// # Properties
 /// The component uses the `ScrollProps` struct for its properties. Key properties include:
 ///
-/// - **style**: Inline styles for the scroll button (`&'static str`). Default: `SCROLL_TO_TOP_STYLE`.
-/// - **class**: CSS classes for styling the button (`&'static str`). Default: `""`.
+/// - **style**: Inline styles for the scroll button (`AttrValue`). Default: `SCROLL_TO_TOP_STYLE`.
+/// - **class**: CSS classes for styling the button (`AttrValue`). Default: `""`.
 /// - **content**: Custom content for the scroll button (`Html`). Default: An SVG icon.
-/// - **behavior**: Scrolling behavior, either `Behavior::Smooth` or `Behavior::Instant`. Default: `Smooth`.
-/// - **top**: Vertical scroll target position (`f64`). Default: `0.0`.
-/// - **left**: Horizontal scroll target position (`f64`). Default: `0.0`.
+/// - **behavior**: Scrolling behavior: `Smooth`, `Instant`, `Auto` or `Animated { duration_ms, easing }`. Default: `Smooth`.
+/// - **target**: Where to scroll to (`ScrollTarget`). Default: the top of the page.
+/// - **container**: Scrollable area to drive and watch (`ScrollContainer`). Default: the window.
 /// - **offset**: Additional offset for the scroll target (`f64`). Default: `0.0`.
 /// - **delay**: Delay before scrolling starts in milliseconds (`u32`). Default: `0`.
 /// - **auto_hide**: Whether the button is visible based on scroll position (`bool`). Default: `true`.
 /// - **threshold**: Scroll position threshold for visibility (`f64`). Default: `20.0`.
 /// - **on_begin**: Callback triggered when scrolling begins (`Callback<()>`). Default: No-op.
-/// - **on_end**: Callback triggered when scrolling ends (`Callback<()>`). Default: No-op.
-/// - **update_hash**: Whether to update the URL hash during scrolling (`bool`). Default: `true`.
-/// - **show_id**: ID of the container that determines the button's visibility (`&'static str`). Default: `""`.
-/// - **scroll_id**: ID of the target container for scrolling (`&'static str`). Default: `""`.
+/// - **on_end**: Callback triggered once scrolling has finished (`Callback<()>`). Default: No-op.
+/// - **on_cancel**: Callback triggered when a pending or running scroll is cancelled (`Callback<()>`). Default: No-op.
+/// - **on_error**: Callback triggered when the scroll cannot be carried out (`Callback<ScrollError>`). Default: No-op.
+/// - **update_hash**: How the URL is updated: `None`, `Push`, `Replace`, `Query` or `Custom` (`HashMode`). Default: `Push`.
+/// - **show_id**: ID of the container that determines the button's visibility (`AttrValue`). Default: `""`.
+/// - **aria_label**: Accessible name of the button (`Option<AttrValue>`). Default: a description of the target.
+/// - **title**: Tooltip of the button (`Option<AttrValue>`). Default: the accessible name.
+/// - **focus_target**: Whether to focus an element target once the scroll has finished (`bool`). Default: `true`.
+/// - **reduced_motion**: How `prefers-reduced-motion` is honoured: `System`, `Always` or `Never` (`ReducedMotion`). Default: `System`.
 ///
 /// # Features
 /// - Automatically hides or shows based on scroll position.
+/// - Renders a native `<button type="button">`, so it is focusable, labelled and activated with Enter or Space.
 /// - Customizable appearance and content (e.g., SVG or HTML).
-/// - Supports smooth and instant scrolling behaviors.
+/// - Supports smooth, instant and custom-eased animated scrolling behaviors.
 /// - Configurable callbacks for when scrolling begins or ends.
 /// - Adjusts scrolling target with offsets and delays.
 ///
@@ -193,9 +293,29 @@ pub struct ScrollProps {
 /// }
 /// ```
 ///
+/// ## Custom Animation
+/// ```rust
+/// use scroll_rs::yew::Scroll;
+/// use scroll_rs::{Behavior, Easing};
+/// use yew::prelude::*;
+///
+/// #[function_component(AnimatedScrollButton)]
+/// pub fn animated_scroll_button() -> Html {
+///     html! {
+///         <Scroll
+///             behavior={Behavior::Animated {
+///                 duration_ms: 800,
+///                 easing: Easing::EaseOutBack,
+///             }}
+///         />
+///     }
+/// }
+/// ```
+///
 /// ## Scroll to Specific Target
 /// ```rust
 /// use scroll_rs::yew::{Scroll, ScrollProps};
+/// use scroll_rs::ScrollTarget;
 /// use yew::prelude::*;
 ///
 /// #[function_component(ScrollToSection)]
@@ -209,7 +329,7 @@ pub struct ScrollProps {
 ///                 <h2>{"Section 2"}</h2>
 ///             </div>
 ///             <Scroll
-///                 scroll_id="section1"
+///                 target={ScrollTarget::id("section1")}
 ///                 style="position: fixed; bottom: 4rem; right: 3rem; background-color: #6B7280; color: #FFFFFF; padding: 1rem; border-radius: 50%;"
 ///                 icon={html! {
 ///                     <span>{"Scroll to Section 1"}</span>
@@ -220,140 +340,127 @@ pub struct ScrollProps {
 /// }
 /// ```
 ///
+/// ## Scroll to a `NodeRef`
+/// ```rust
+/// use scroll_rs::yew::Scroll;
+/// use scroll_rs::ScrollTarget;
+/// use yew::prelude::*;
+///
+/// #[function_component(ScrollToRef)]
+/// pub fn scroll_to_ref() -> Html {
+///     let details = use_node_ref();
+///     html! {
+///         <>
+///             <section ref={details.clone()}>{"Details"}</section>
+///             <Scroll target={ScrollTarget::from(details)} />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// ## Report Views to Analytics
+/// ```rust
+/// use scroll_rs::yew::Scroll;
+/// use scroll_rs::{HashMode, ScrollTarget};
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct TrackedProps {
+///     pub on_view: Callback<String>,
+/// }
+///
+/// #[function_component(Tracked)]
+/// pub fn tracked(props: &TrackedProps) -> Html {
+///     let on_view = props.on_view.clone();
+///     html! {
+///         <Scroll
+///             target={ScrollTarget::id("pricing")}
+///             update_hash={HashMode::custom(move |id| on_view.emit(id.to_string()))}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Inside a Scrollable Panel
+/// ```rust
+/// use scroll_rs::yew::Scroll;
+/// use scroll_rs::ScrollContainer;
+/// use yew::prelude::*;
+///
+/// #[function_component(Sidebar)]
+/// pub fn sidebar() -> Html {
+///     html! {
+///         <aside id="sidebar" style="height: 400px; overflow-y: auto;">
+///             <div style="height: 2000px;" />
+///             <Scroll
+///                 container={ScrollContainer::id("sidebar")}
+///                 style="position: sticky; bottom: 1rem; margin-left: auto; width: fit-content;"
+///             />
+///         </aside>
+///     }
+/// }
+/// ```
+///
 /// # Behavior
 /// - The component uses an internal `use_state` hook to manage the visibility of the scroll button.
-/// - It attaches a scroll event listener to monitor the current scroll position and determines visibility based on
+/// - It attaches a scroll event listener to the `container` to monitor its scroll position and determines visibility based on
 ///   the `threshold` or `show_id` container position.
 /// - Clicking the button triggers the scroll action, which can optionally include a delay and emit the `on_begin`
 ///   and `on_end` callbacks.
 ///
 /// # Notes
-/// - Ensure that `scroll_id` and `show_id` refer to valid element IDs in your DOM.
+/// - Ensure that element targets and `show_id` refer to elements in your DOM.
+/// - To render your own trigger, such as a design-system button, use [`HeadlessScroll`], which runs the same logic
+///   without any markup.
+/// - Safe to render on the server. The first render only depends on the props: the button starts hidden when
+///   `auto_hide` is on and shown otherwise, and the DOM is only touched from effects and event handlers, so hydration
+///   never mismatches.
 #[function_component(Scroll)]
 pub fn scroll(props: &ScrollProps) -> Html {
-    let visible_handle = use_state(|| false);
-    let is_visible = *visible_handle;
-
-    let behavior = props.behavior.clone();
-    let top = props.top;
-    let left = props.left;
-    let offset = props.offset;
-    let delay = props.delay;
-    let on_begin = props.on_begin.clone();
-    let on_end = props.on_end.clone();
-    let update_hash = props.update_hash;
-    let threshold = props.threshold;
-    let show_id = props.show_id;
-    let scroll_id = props.scroll_id;
-    let auto_hide = props.auto_hide;
-
-    let container_element: Option<Element> =
-        window().document().unwrap().get_element_by_id(show_id);
-
-    use_effect_with((), move |_| {
-        let listener = if auto_hide {
-            Some(EventListener::new(&window(), "scroll", move |_| {
-                if let Some(container) = &container_element {
-                    let container_position = container.get_bounding_client_rect().top();
-                    let scroll_position = window().scroll_y().unwrap_or(0.0);
-                    visible_handle.set(scroll_position > container_position);
-                } else {
-                    let scroll_position = window().scroll_y().unwrap_or(0.0);
-                    visible_handle.set(scroll_position > threshold);
-                };
-            }))
-        } else {
-            None
-        };
-        move || {
-            drop(listener);
-        }
-    });
-
-    let on_click = {
-        Callback::from(move |_| {
-            if delay > 0 {
-                let on_begin = on_begin.clone();
-                let on_end = on_end.clone();
-                let behavior = behavior.clone();
-                gloo::timers::callback::Timeout::new(delay, move || {
-                    on_begin.emit(());
-                    scroll_to(
-                        top,
-                        left,
-                        offset,
-                        behavior.clone(),
-                        update_hash,
-                        Some(scroll_id.to_string()),
-                    );
-                    on_end.emit(());
-                })
-                .forget();
-            } else {
-                on_begin.emit(());
-                scroll_to(
-                    top,
-                    left,
-                    offset,
-                    behavior.clone(),
-                    update_hash,
-                    Some(scroll_id.to_string()),
-                );
-                on_end.emit(());
+    let render = {
+        let (class, style, icon) = (props.class.clone(), props.style.clone(), props.icon.clone());
+        let title = props.title.clone();
+        Callback::from(move |trigger: ScrollTrigger| {
+            let onclick = trigger.scroll.reform(|_: MouseEvent| ());
+            let label = AttrValue::from(trigger.aria.label);
+            let title = title.clone().unwrap_or_else(|| label.clone());
+            html! {
+                if trigger.visible {
+                    <button
+                        type="button"
+                        class={class.clone()}
+                        style={style.clone()}
+                        aria-label={label}
+                        {title}
+                        {onclick}
+                    >
+                        { icon.clone() }
+                    </button>
+                }
             }
         })
     };
 
     html! {
-        if is_visible {
-            <div class={props.class} style={props.style} onclick={on_click}>
-                { props.icon.clone() }
-            </div>
-        }
-    }
-}
-
-/// A helper function to scroll to a specific position.
-fn scroll_to(
-    top: f64,
-    left: f64,
-    offset: f64,
-    behavior: Behavior,
-    update_hash: bool,
-    scroll_id: Option<String>,
-) {
-    let options = ScrollToOptions::new();
-    let container_element: Option<Element> = window()
-        .document()
-        .unwrap()
-        .get_element_by_id(&scroll_id.clone().unwrap_or_default());
-    options.set_left(left);
-    match behavior {
-        Behavior::Auto => {
-            options.set_behavior(ScrollBehavior::Auto);
-        }
-        Behavior::Instant => {
-            options.set_behavior(ScrollBehavior::Instant);
-        }
-        Behavior::Smooth => {
-            options.set_behavior(ScrollBehavior::Smooth);
-        }
-    }
-    if let Some(container) = &container_element {
-        let container_position = container.get_bounding_client_rect().top();
-        options.set_top(container_position);
-    } else {
-        options.set_top(top + offset);
-    };
-    window().scroll_with_scroll_to_options(&options);
-
-    if update_hash {
-        let hash = format!("#{}", scroll_id.unwrap_or_default());
-        window()
-            .history()
-            .unwrap()
-            .push_state_with_url(&JsValue::NULL, "", Some(&hash))
-            .unwrap();
+        <HeadlessScroll
+            {render}
+            behavior={props.behavior.clone()}
+            target={props.target.clone()}
+            container={props.container.clone()}
+            offset={props.offset}
+            delay={props.delay}
+            auto_hide={props.auto_hide}
+            threshold={props.threshold}
+            on_begin={props.on_begin.clone()}
+            on_end={props.on_end.clone()}
+            on_cancel={props.on_cancel.clone()}
+            on_error={props.on_error.clone()}
+            update_hash={props.update_hash.clone()}
+            show_id={props.show_id.clone()}
+            aria_label={props.aria_label.clone()}
+            focus_target={props.focus_target}
+            reduced_motion={props.reduced_motion}
+        />
     }
 }
 
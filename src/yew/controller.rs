@@ -0,0 +1,46 @@
+use crate::common::{ScrollController, ScrollOptions};
+use yew::prelude::*;
+
+/// Scrolls from your own code, e.g. after a form submit or on a keyboard
+/// shortcut.
+///
+/// Returns a [`ScrollController`] with `scroll_to`, `scroll_by`,
+/// `scroll_into_view`, `scroll_to_top`, `scroll_to_bottom` and `stop`. It
+/// scrolls exactly like the `Scroll` component, including its `on_begin`,
+/// `on_end`, `on_cancel` and `on_error` callbacks, which can be set on the
+/// controller. The latest `options` are used for every scroll, and a running
+/// scroll is cancelled on unmount.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll;
+/// use scroll_rs::{ScrollOptions, ScrollTarget};
+/// use yew::prelude::*;
+///
+/// #[function_component(Signup)]
+/// pub fn signup() -> Html {
+///     let scroll = use_scroll(ScrollOptions { offset: -64.0, ..Default::default() });
+///     let onsubmit = Callback::from(move |event: SubmitEvent| {
+///         event.prevent_default();
+///         scroll.scroll_to(ScrollTarget::id("confirmation"));
+///     });
+///     html! { <form {onsubmit}><button>{ "Sign up" }</button></form> }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll(options: ScrollOptions) -> ScrollController {
+    let controller = use_memo((), |_| ScrollController::new(ScrollOptions::default()));
+    controller.set_options(options);
+
+    {
+        let controller = controller.clone();
+        use_effect_with((), move |_| {
+            move || {
+                controller.stop();
+            }
+        });
+    }
+
+    (*controller).clone()
+}
@@ -0,0 +1,31 @@
+use crate::common::{HashScroll, HashScrollOptions};
+use yew::prelude::*;
+
+/// Scrolls to the element named by the URL hash on mount and on every
+/// `hashchange`.
+///
+/// Shared links such as `/docs#install` then land on the section with the
+/// configured `offset` and `behavior` applied, even when the section is
+/// rendered after the page loads. The listener is recreated whenever `options`
+/// change and is removed on unmount.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll_to_hash;
+/// use scroll_rs::HashScrollOptions;
+/// use yew::prelude::*;
+///
+/// #[function_component(Docs)]
+/// pub fn docs() -> Html {
+///     use_scroll_to_hash(HashScrollOptions { offset: -64.0, ..Default::default() });
+///     html! { <section id="install">{ "Install" }</section> }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_to_hash(options: HashScrollOptions) {
+    use_effect_with(options, |options| {
+        let hash_scroll = HashScroll::start(options.clone());
+        move || drop(hash_scroll)
+    });
+}
@@ -0,0 +1,231 @@
+use crate::common::{
+    Behavior, HashMode, ReducedMotion, ScrollAria, ScrollContainer, ScrollEngine, ScrollError,
+    ScrollOptions, ScrollTarget,
+};
+use crate::yew::use_scroll;
+use yew::prelude::*;
+
+/// State handed to the render function of [`HeadlessScroll`].
+#[derive(Clone, PartialEq)]
+pub struct ScrollTrigger {
+    /// Whether the trigger should be shown, following `auto_hide`, `threshold`
+    /// and `show_id`.
+    pub visible: bool,
+    /// How far the container has been scrolled through, from `0.0` to `1.0`.
+    pub progress: f64,
+    /// Whether a scroll is pending or running.
+    pub is_scrolling: bool,
+    /// Starts the scroll, or cancels the one in progress, exactly like a click
+    /// on the `Scroll` button.
+    pub scroll: Callback<()>,
+    /// ARIA attributes describing the trigger.
+    pub aria: ScrollAria,
+}
+
+/// Properties for configuring the `HeadlessScroll` component.
+///
+/// These are the props of `Scroll` that control visibility and scrolling.
+/// Everything visual is left to `render`.
+#[derive(Properties, Clone, PartialEq)]
+pub struct HeadlessScrollProps {
+    /// Renders the trigger from the current [`ScrollTrigger`] state.
+    ///
+    /// Called on every change, including while the trigger is hidden, so it can
+    /// animate out instead of disappearing.
+    pub render: Callback<ScrollTrigger, Html>,
+
+    /// Behavior of the scroll action. Defaults to `Behavior::Smooth`.
+    #[prop_or(Behavior::Smooth)]
+    pub behavior: Behavior,
+
+    /// Target of the scroll action. Defaults to the top of the page.
+    #[prop_or_default]
+    pub target: ScrollTarget,
+
+    /// Scrollable area the trigger drives and watches. Defaults to the window.
+    #[prop_or_default]
+    pub container: ScrollContainer,
+
+    /// Additional offset in pixels for the scroll target. Defaults to `0.0`.
+    #[prop_or(0.0)]
+    pub offset: f64,
+
+    /// Delay before initiating the scroll action, in milliseconds. Defaults to `0`.
+    #[prop_or(0)]
+    pub delay: u32,
+
+    /// Whether `visible` follows the scroll position. Defaults to `true`.
+    #[prop_or(true)]
+    pub auto_hide: bool,
+
+    /// Scroll position in pixels after which the trigger becomes visible.
+    /// Defaults to `20.0`.
+    #[prop_or(20.0)]
+    pub threshold: f64,
+
+    /// Callback triggered when scrolling begins. Defaults to no-op.
+    #[prop_or_default]
+    pub on_begin: Callback<()>,
+
+    /// Callback triggered once the scroll has actually finished. Defaults to no-op.
+    #[prop_or_default]
+    pub on_end: Callback<()>,
+
+    /// Callback triggered when a scroll is cancelled before it finishes.
+    /// Defaults to no-op.
+    #[prop_or_default]
+    pub on_cancel: Callback<()>,
+
+    /// Callback triggered when a scroll cannot be carried out. Defaults to no-op.
+    #[prop_or_default]
+    pub on_error: Callback<ScrollError>,
+
+    /// How the URL is updated when scrolling to an element with an ID. Accepts
+    /// a `bool` as well. Defaults to `HashMode::Push`.
+    #[prop_or_default]
+    pub update_hash: HashMode,
+
+    /// ID of a container whose scroll position controls visibility.
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub show_id: AttrValue,
+
+    /// Accessible name of the trigger, handed out as `aria.label`.
+    /// Defaults to a description of the target, such as "Scroll to top".
+    #[prop_or_default]
+    pub aria_label: Option<AttrValue>,
+
+    /// Whether to move keyboard focus to element targets once the scroll has
+    /// finished. Defaults to `true`.
+    #[prop_or(true)]
+    pub focus_target: bool,
+
+    /// Whether smooth and animated scrolls become instant when the user
+    /// prefers reduced motion. Defaults to `ReducedMotion::System`.
+    #[prop_or_default]
+    pub reduced_motion: ReducedMotion,
+}
+
+/// HeadlessScroll Component
+///
+/// The logic of [`Scroll`](crate::yew::Scroll) without any markup: it tracks
+/// visibility, progress and the running scroll, and hands them to `render`
+/// together with a `scroll` action and ARIA attributes. Use it to turn a button
+/// from your own design system into a scroll trigger.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::{HeadlessScroll, ScrollTrigger};
+/// use yew::prelude::*;
+///
+/// #[function_component(BackToTop)]
+/// pub fn back_to_top() -> Html {
+///     let render = Callback::from(|trigger: ScrollTrigger| {
+///         let onclick = trigger.scroll.reform(|_: MouseEvent| ());
+///         html! {
+///             <button
+///                 class={classes!("fab", trigger.visible.then_some("fab-visible"))}
+///                 aria-label={trigger.aria.label}
+///                 aria-hidden={trigger.aria.hidden.to_string()}
+///                 aria-busy={trigger.aria.busy.to_string()}
+///                 {onclick}
+///             >
+///                 { format!("{:.0}%", trigger.progress * 100.0) }
+///             </button>
+///         }
+///     });
+///     html! { <HeadlessScroll {render} /> }
+/// }
+/// ```
+#[function_component(HeadlessScroll)]
+pub fn headless_scroll(props: &HeadlessScrollProps) -> Html {
+    // Scroll events fire far more often than these values change, and a
+    // re-render is only needed when they do.
+    let visible = use_state_eq(|| !props.auto_hide);
+    let progress = use_state_eq(|| 0.0);
+    let is_scrolling = use_state_eq(|| false);
+
+    let controller = use_scroll(ScrollOptions {
+        container: props.container.clone(),
+        offset: props.offset,
+        behavior: props.behavior.clone(),
+        update_hash: props.update_hash.clone(),
+        delay_ms: props.delay,
+        focus_target: props.focus_target,
+        reduced_motion: props.reduced_motion,
+    });
+    {
+        let on_begin = props.on_begin.clone();
+        let (on_end, ended) = (props.on_end.clone(), is_scrolling.clone());
+        let (on_cancel, cancelled) = (props.on_cancel.clone(), is_scrolling.clone());
+        let (on_error, failed) = (props.on_error.clone(), is_scrolling.clone());
+        controller
+            .on_begin(move || on_begin.emit(()))
+            .on_end(move || {
+                ended.set(false);
+                on_end.emit(());
+            })
+            .on_cancel(move || {
+                cancelled.set(false);
+                on_cancel.emit(());
+            })
+            .on_error(move |error| {
+                failed.set(false);
+                on_error.emit(error);
+            });
+    }
+
+    {
+        let (visible, progress) = (visible.clone(), progress.clone());
+        use_effect_with(
+            (
+                props.container.clone(),
+                props.auto_hide,
+                props.threshold,
+                props.show_id.clone(),
+            ),
+            move |(container, auto_hide, threshold, show_id)| {
+                let (auto_hide, threshold, show_id) = (*auto_hide, *threshold, show_id.clone());
+                if !auto_hide {
+                    visible.set(true);
+                }
+                let listener = ScrollEngine::with_container(container).ok().map(|engine| {
+                    let update = move |engine: &ScrollEngine| {
+                        visible.set(!auto_hide || engine.is_past(threshold, &show_id));
+                        progress.set(engine.progress(None));
+                    };
+                    update(&engine);
+                    let root = engine.root().clone();
+                    root.on_scroll(move || update(&engine))
+                });
+                move || drop(listener)
+            },
+        );
+    }
+
+    let scroll = {
+        let target = props.target.clone();
+        let is_scrolling = is_scrolling.clone();
+        Callback::from(move |()| {
+            // A second call while a scroll is pending or running cancels it.
+            if !controller.stop() {
+                let task = controller.scroll_to(target.clone());
+                is_scrolling.set(task.is_running());
+            }
+        })
+    };
+
+    let mut aria = ScrollAria::new(&props.target, *visible, *is_scrolling);
+    if let Some(label) = &props.aria_label {
+        aria.label = label.to_string();
+    }
+
+    props.render.emit(ScrollTrigger {
+        visible: *visible,
+        progress: *progress,
+        is_scrolling: *is_scrolling,
+        scroll,
+        aria,
+    })
+}
@@ -0,0 +1,126 @@
+use crate::common::{ScrollContainer, SentinelObserver, SENTINEL_ROOT_MARGIN};
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Properties for configuring the `InfiniteScroll` component.
+///
+/// The component renders its children followed by an invisible sentinel and
+/// asks for more content when the sentinel nears the visible area.
+#[derive(Properties, Clone, PartialEq)]
+pub struct InfiniteScrollProps {
+    /// The items loaded so far.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Callback triggered when the end of the list comes into reach.
+    ///
+    /// Not triggered while `loading` is `true` or once `has_more` is `false`.
+    pub on_reach_end: Callback<()>,
+
+    /// Whether a page is currently being fetched.
+    ///
+    /// Set this to `true` from `on_reach_end` and back to `false` once the page
+    /// has been appended to prevent duplicate requests. Defaults to `false`.
+    #[prop_or(false)]
+    pub loading: bool,
+
+    /// Whether there is more content to load.
+    ///
+    /// Defaults to `true`.
+    #[prop_or(true)]
+    pub has_more: bool,
+
+    /// `IntersectionObserver` root margin, in CSS shorthand.
+    ///
+    /// Defaults to `"0px 0px 200px 0px"`, which fires 200px before the end of
+    /// the list becomes visible.
+    #[prop_or_else(|| SENTINEL_ROOT_MARGIN.to_string())]
+    pub root_margin: String,
+
+    /// Scrollable area the list lives in.
+    ///
+    /// Defaults to the window.
+    #[prop_or_default]
+    pub container: ScrollContainer,
+}
+
+/// InfiniteScroll Component
+///
+/// A Yew component for feeds that load page by page. It renders its children followed by a
+/// sentinel element watched by an `IntersectionObserver`, and emits `on_reach_end` when the
+/// sentinel enters the visible area of the window or the configured container. While `loading` is
+/// `true` or `has_more` is `false` the sentinel is not observed, so each page is requested once.
+/// If the list is still too short to fill the container after a page loads, the next page is
+/// requested right away.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::InfiniteScroll;
+/// use yew::platform::spawn_local;
+/// use yew::prelude::*;
+///
+/// #[function_component(Feed)]
+/// pub fn feed() -> Html {
+///     let items = use_state(|| (0..20).collect::<Vec<u32>>());
+///     let loading = use_state(|| false);
+///
+///     let on_reach_end = {
+///         let items = items.clone();
+///         let loading = loading.clone();
+///         Callback::from(move |_| {
+///             loading.set(true);
+///             let (items, loading) = (items.clone(), loading.clone());
+///             spawn_local(async move {
+///                 let mut next = (*items).clone();
+///                 next.extend(fetch_page(next.len()).await);
+///                 items.set(next);
+///                 loading.set(false);
+///             });
+///         })
+///     };
+///
+///     html! {
+///         <InfiniteScroll {on_reach_end} loading={*loading} has_more={items.len() < 200}>
+///             { for items.iter().map(|item| html! { <p>{ item }</p> }) }
+///         </InfiniteScroll>
+///     }
+/// }
+/// # async fn fetch_page(from: usize) -> Vec<u32> { (from as u32..from as u32 + 20).collect() }
+/// ```
+#[function_component(InfiniteScroll)]
+pub fn infinite_scroll(props: &InfiniteScrollProps) -> Html {
+    let sentinel = use_node_ref();
+
+    {
+        let sentinel = sentinel.clone();
+        let on_reach_end = props.on_reach_end.clone();
+        use_effect_with(
+            (
+                props.container.clone(),
+                props.root_margin.clone(),
+                props.loading,
+                props.has_more,
+            ),
+            move |(container, root_margin, loading, has_more)| {
+                let observer = if !*loading && *has_more {
+                    sentinel.cast::<Element>().and_then(|sentinel| {
+                        SentinelObserver::observe(&sentinel, container, root_margin, move || {
+                            on_reach_end.emit(())
+                        })
+                    })
+                } else {
+                    None
+                };
+                move || drop(observer)
+            },
+        );
+    }
+
+    html! {
+        <>
+            { props.children.clone() }
+            <div ref={sentinel} aria-hidden="true" style="height: 1px;" />
+        </>
+    }
+}
@@ -0,0 +1,46 @@
+use crate::common::{ScrollLock, ScrollLockOptions};
+use yew::prelude::*;
+
+/// Locks the page scroll while `locked` is `true`.
+///
+/// Meant for modals and drawers: the page behind them stops scrolling while
+/// the elements in `options.allow` keep scrolling. Nested locks are reference
+/// counted, so the page is only unlocked once every component holding a lock
+/// has released it. The lock is also released on unmount.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll_lock;
+/// use scroll_rs::{ScrollContainer, ScrollLockOptions};
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct ModalProps {
+///     pub open: bool,
+///     pub children: Html,
+/// }
+///
+/// #[function_component(Modal)]
+/// pub fn modal(props: &ModalProps) -> Html {
+///     use_scroll_lock(
+///         props.open,
+///         ScrollLockOptions {
+///             allow: vec![ScrollContainer::id("modal-body")],
+///             ..Default::default()
+///         },
+///     );
+///     html! {
+///         if props.open {
+///             <div class="modal"><div id="modal-body">{ props.children.clone() }</div></div>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_lock(locked: bool, options: ScrollLockOptions) {
+    use_effect_with((locked, options), |(locked, options)| {
+        let lock = locked.then(|| ScrollLock::acquire(options));
+        move || drop(lock)
+    });
+}
@@ -0,0 +1,148 @@
+use crate::common::{ScrollContainer, ScrollEngine, ScrollTarget, SCROLL_PROGRESS_STYLE};
+use yew::prelude::*;
+
+/// Properties for configuring the `ScrollProgress` component.
+///
+/// The component renders a bar whose width follows how far the window, a
+/// scrollable container or a single element has been scrolled through.
+#[derive(Properties, Clone, PartialEq)]
+pub struct ScrollProgressProps {
+    /// Custom inline styles for the progress bar.
+    ///
+    /// Accepts any `AttrValue`, so styles can be computed at runtime. The
+    /// bar's `width` is appended to these styles on every update.
+    /// Defaults to the built-in `SCROLL_PROGRESS_STYLE`.
+    #[prop_or(AttrValue::Static(SCROLL_PROGRESS_STYLE))]
+    pub style: AttrValue,
+
+    /// Custom CSS classes for the progress bar.
+    ///
+    /// Accepts any `AttrValue`. Defaults to an empty string.
+    #[prop_or_default]
+    pub class: AttrValue,
+
+    /// Scrollable area whose progress is measured.
+    ///
+    /// Defaults to the window.
+    #[prop_or_default]
+    pub container: ScrollContainer,
+
+    /// Element to measure progress through, such as an article.
+    ///
+    /// Progress starts when the element's top reaches the top of the container
+    /// and completes when its bottom comes into view. Defaults to `None`, which
+    /// measures the whole container.
+    #[prop_or_default]
+    pub element: Option<ScrollTarget>,
+
+    /// Callback triggered with the new progress (`0.0..=1.0`) whenever it changes.
+    ///
+    /// Defaults to no-op.
+    #[prop_or_default]
+    pub on_change: Callback<f64>,
+}
+
+/// ScrollProgress Component
+///
+/// A Yew component that renders a reading-progress bar. The bar fills from `0%` to `100%` as the
+/// window, a scrollable container or a single element is scrolled through. Use
+/// [`use_scroll_progress`] instead to render the progress in any other way.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::ScrollProgress;
+/// use scroll_rs::ScrollTarget;
+/// use yew::prelude::*;
+///
+/// #[function_component(Post)]
+/// pub fn post() -> Html {
+///     html! {
+///         <>
+///             <ScrollProgress element={ScrollTarget::id("article")} />
+///             <article id="article" class="h-[3000px]" />
+///         </>
+///     }
+/// }
+/// ```
+#[function_component(ScrollProgress)]
+pub fn scroll_progress(props: &ScrollProgressProps) -> Html {
+    let progress = use_progress(
+        props.container.clone(),
+        props.element.clone(),
+        props.on_change.clone(),
+    );
+    let percent = progress * 100.0;
+
+    html! {
+        <div
+            class={props.class.clone()}
+            style={format!("{} width: {percent}%;", props.style)}
+            role="progressbar"
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow={percent.round().to_string()}
+        />
+    }
+}
+
+/// Tracks how far `container`, or `element` within it, has been scrolled
+/// through.
+///
+/// Returns the progress from `0.0` to `1.0`. The listener is recreated whenever
+/// `container` or `element` change and is removed on unmount.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll_progress;
+/// use scroll_rs::ScrollContainer;
+/// use yew::prelude::*;
+///
+/// #[function_component(Percent)]
+/// pub fn percent() -> Html {
+///     let progress = use_scroll_progress(ScrollContainer::Window, None);
+///     html! { <span>{ format!("{:.0}%", progress * 100.0) }</span> }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_progress(container: ScrollContainer, element: Option<ScrollTarget>) -> f64 {
+    use_progress(container, element, Callback::noop())
+}
+
+#[hook]
+fn use_progress(
+    container: ScrollContainer,
+    element: Option<ScrollTarget>,
+    on_change: Callback<f64>,
+) -> f64 {
+    let progress = use_state_eq(|| 0.0);
+    // Read by the listener on every change, so a new callback takes effect
+    // without recreating the listener.
+    let on_change_ref = use_mut_ref(Callback::noop);
+    *on_change_ref.borrow_mut() = on_change;
+
+    {
+        let progress = progress.clone();
+        use_effect_with((container, element), move |(container, element)| {
+            let element = element.clone();
+            let listener = ScrollEngine::with_container(container).ok().map(|engine| {
+                let mut last = engine.progress(element.as_ref());
+                progress.set(last);
+                let root = engine.root().clone();
+                root.on_scroll(move || {
+                    let value = engine.progress(element.as_ref());
+                    if value != last {
+                        last = value;
+                        progress.set(value);
+                        let on_change = on_change_ref.borrow().clone();
+                        on_change.emit(value);
+                    }
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    *progress
+}
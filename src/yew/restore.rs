@@ -0,0 +1,55 @@
+use crate::common::{ScrollRestoration, ScrollRestorationOptions};
+use yew::prelude::*;
+
+/// Saves the scroll position of every history entry and restores it on
+/// back/forward navigation.
+///
+/// Pass a key that identifies the current route, such as the path from
+/// `yew_router`'s `use_location`, and call the hook once near the root of the
+/// app. Fresh navigations scroll to the URL hash target, or to the top when
+/// there is none. The saved positions live as long as the calling component.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll_restoration;
+/// use scroll_rs::ScrollRestorationOptions;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// pub struct LayoutProps {
+///     pub path: String,
+///     pub children: Html,
+/// }
+///
+/// #[function_component(Layout)]
+/// pub fn layout(props: &LayoutProps) -> Html {
+///     use_scroll_restoration(props.path.clone(), ScrollRestorationOptions::default());
+///     props.children.clone()
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_restoration(route: String, options: ScrollRestorationOptions) {
+    let restoration = use_mut_ref(|| None::<ScrollRestoration>);
+
+    {
+        let restoration = restoration.clone();
+        let route = route.clone();
+        use_effect_with(options, move |options| {
+            let created = ScrollRestoration::new(options.clone());
+            if let Some(created) = &created {
+                created.navigate(&route);
+            }
+            *restoration.borrow_mut() = created;
+            move || {
+                restoration.borrow_mut().take();
+            }
+        });
+    }
+
+    use_effect_with(route, move |route| {
+        if let Some(restoration) = restoration.borrow().as_ref() {
+            restoration.navigate(route);
+        }
+    });
+}
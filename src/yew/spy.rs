@@ -0,0 +1,147 @@
+use crate::common::{ScrollContainer, ScrollSpyObserver, ScrollSpyOptions};
+use yew::prelude::*;
+
+/// Properties for configuring the `ScrollSpy` component.
+///
+/// The component tracks which of a list of sections is currently in view and
+/// reports changes through `on_change`, e.g. to highlight navigation links.
+#[derive(Properties, Clone, PartialEq)]
+pub struct ScrollSpyProps {
+    /// IDs of the sections to track, in document order.
+    pub ids: Vec<String>,
+
+    /// Offset in pixels added to the top of the observed area.
+    ///
+    /// Uses the same convention as `Scroll`'s `offset`: pass the negative height
+    /// of a fixed header so sections hidden behind it are ignored. Defaults to `0.0`.
+    #[prop_or(0.0)]
+    pub offset: f64,
+
+    /// `IntersectionObserver` root margin, in CSS shorthand.
+    ///
+    /// Defaults to `"0px 0px -50% 0px"`, i.e. the upper half of the viewport.
+    #[prop_or_else(|| ScrollSpyOptions::default().root_margin)]
+    pub root_margin: String,
+
+    /// Fraction of a section that must be visible for it to count as in view.
+    ///
+    /// Defaults to `0.0`.
+    #[prop_or(0.0)]
+    pub threshold: f64,
+
+    /// Scrollable area the sections live in.
+    ///
+    /// Defaults to the window.
+    #[prop_or_default]
+    pub container: ScrollContainer,
+
+    /// Callback triggered with the new active section ID whenever it changes.
+    ///
+    /// Defaults to no-op.
+    #[prop_or_default]
+    pub on_change: Callback<Option<String>>,
+
+    /// Content rendered as-is inside the spy.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// ScrollSpy Component
+///
+/// A Yew component that tracks which section of a long page is currently in view using an
+/// `IntersectionObserver`, and emits `on_change` with the active section ID. It renders its
+/// children unchanged, so it can wrap a navigation menu or sit anywhere in the tree.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::ScrollSpy;
+/// use yew::prelude::*;
+///
+/// #[function_component(Docs)]
+/// pub fn docs() -> Html {
+///     let active = use_state(|| None::<String>);
+///     let on_change = {
+///         let active = active.clone();
+///         Callback::from(move |id| active.set(id))
+///     };
+///     let ids = vec!["intro".to_string(), "install".to_string(), "usage".to_string()];
+///
+///     html! {
+///         <ScrollSpy ids={ids.clone()} offset={-64.0} {on_change}>
+///             <nav>
+///                 { for ids.iter().map(|id| {
+///                     let class = (active.as_deref() == Some(id.as_str())).then_some("active");
+///                     html! { <a href={format!("#{id}")} {class}>{ id }</a> }
+///                 }) }
+///             </nav>
+///         </ScrollSpy>
+///     }
+/// }
+/// ```
+#[function_component(ScrollSpy)]
+pub fn scroll_spy(props: &ScrollSpyProps) -> Html {
+    let options = ScrollSpyOptions {
+        offset: props.offset,
+        root_margin: props.root_margin.clone(),
+        threshold: props.threshold,
+        container: props.container.clone(),
+    };
+    use_spy(props.ids.clone(), options, props.on_change.clone());
+
+    props.children.clone()
+}
+
+/// Tracks which of the sections with the given `ids` is currently in view.
+///
+/// Returns the ID of the active section, or `None` until a section scrolls into
+/// view. The observer is recreated whenever `ids` or `options` change and is
+/// disconnected on unmount.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll_spy;
+/// use scroll_rs::ScrollSpyOptions;
+/// use yew::prelude::*;
+///
+/// #[function_component(Toc)]
+/// pub fn toc() -> Html {
+///     let active = use_scroll_spy(
+///         vec!["intro".into(), "usage".into()],
+///         ScrollSpyOptions { offset: -64.0, ..Default::default() },
+///     );
+///     html! { <p>{ format!("Reading: {}", active.unwrap_or_default()) }</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_spy(ids: Vec<String>, options: ScrollSpyOptions) -> Option<String> {
+    use_spy(ids, options, Callback::noop())
+}
+
+#[hook]
+fn use_spy(
+    ids: Vec<String>,
+    options: ScrollSpyOptions,
+    on_change: Callback<Option<String>>,
+) -> Option<String> {
+    let active = use_state(|| None);
+    // Read by the observer on every change, so a new callback takes effect
+    // without recreating the observer.
+    let on_change_ref = use_mut_ref(Callback::noop);
+    *on_change_ref.borrow_mut() = on_change;
+
+    {
+        let active = active.clone();
+        use_effect_with((ids, options), move |(ids, options)| {
+            let observer = ScrollSpyObserver::observe(ids, options, move |id| {
+                active.set(id.clone());
+                let on_change = on_change_ref.borrow().clone();
+                on_change.emit(id);
+            });
+            move || drop(observer)
+        });
+    }
+
+    (*active).clone()
+}
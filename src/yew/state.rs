@@ -0,0 +1,83 @@
+use crate::common::{ScrollDirection, ScrollState, ScrollStateObserver, ScrollStateOptions};
+use yew::prelude::*;
+
+/// Tracks the position and movement of the window or a scrollable container.
+///
+/// Returns the current [`ScrollState`]: position, direction, velocity and
+/// whether the root is at the top or bottom. The component re-renders only
+/// when the state changes. The listener is recreated whenever `options`
+/// change and is removed on unmount.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll_state;
+/// use scroll_rs::ScrollStateOptions;
+/// use yew::prelude::*;
+///
+/// #[function_component(Position)]
+/// pub fn position() -> Html {
+///     let state = use_scroll_state(ScrollStateOptions::default());
+///     html! {
+///         <p>{ format!("{:.0}px, {:?} at {:.0}px/s", state.top, state.direction, state.velocity) }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_state(options: ScrollStateOptions) -> ScrollState {
+    let state = use_state(ScrollState::default);
+
+    {
+        let state = state.clone();
+        use_effect_with(options, move |options| {
+            let observer = ScrollStateObserver::observe(options, move |next| state.set(next));
+            move || drop(observer)
+        });
+    }
+
+    *state
+}
+
+/// Tracks which way the user last scrolled.
+///
+/// Returns [`ScrollDirection::Idle`] until the root first moves further than
+/// the tolerance. Unlike [`ScrollState::direction`], the last direction is
+/// kept once scrolling settles, which is what a header that hides on the way
+/// down and reappears on the way up needs.
+///
+/// # Examples
+///
+/// ```rust
+/// use scroll_rs::yew::use_scroll_direction;
+/// use scroll_rs::{ScrollDirection, ScrollStateOptions};
+/// use yew::prelude::*;
+///
+/// #[function_component(Header)]
+/// pub fn header() -> Html {
+///     let direction = use_scroll_direction(ScrollStateOptions::default());
+///     let style = if direction == ScrollDirection::Down {
+///         "transform: translateY(-100%);"
+///     } else {
+///         "transform: none;"
+///     };
+///     html! { <header {style}>{ "My Site" }</header> }
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_direction(options: ScrollStateOptions) -> ScrollDirection {
+    let direction = use_state_eq(ScrollDirection::default);
+
+    {
+        let direction = direction.clone();
+        use_effect_with(options, move |options| {
+            let observer = ScrollStateObserver::observe(options, move |state| {
+                if state.direction != ScrollDirection::Idle {
+                    direction.set(state.direction);
+                }
+            });
+            move || drop(observer)
+        });
+    }
+
+    *direction
+}
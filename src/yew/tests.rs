@@ -0,0 +1,71 @@
+use crate::common::testing::{assert_released, live_subscribers, mount_point, settle};
+use crate::yew::{Scroll, ScrollProgress};
+use std::cell::Cell;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+use web_sys::{Event, HtmlElement};
+use yew::prelude::*;
+
+#[function_component(Page)]
+fn page() -> Html {
+    html! {
+        <>
+            <Scroll auto_hide={true} />
+            <ScrollProgress />
+        </>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn remounting_does_not_accumulate_listeners() {
+    assert_released();
+    for _ in 0..20 {
+        let app = yew::Renderer::<Page>::with_root(mount_point().into()).render();
+        settle().await;
+        assert_eq!(live_subscribers(), 2);
+
+        app.destroy();
+        settle().await;
+        assert_released();
+    }
+}
+
+thread_local! {
+    static BEGINS: Cell<u32> = const { Cell::new(0) };
+    static CANCELS: Cell<u32> = const { Cell::new(0) };
+}
+
+#[function_component(DelayedScroll)]
+fn delayed_scroll() -> Html {
+    let on_begin = Callback::from(|()| BEGINS.with(|begins| begins.set(begins.get() + 1)));
+    let on_cancel = Callback::from(|()| CANCELS.with(|cancels| cancels.set(cancels.get() + 1)));
+    html! {
+        <Scroll auto_hide={false} delay={10_000} {on_begin} {on_cancel} />
+    }
+}
+
+#[wasm_bindgen_test]
+async fn tapping_the_button_again_cancels_once() {
+    let root = mount_point();
+    let app = yew::Renderer::<DelayedScroll>::with_root(root.clone().into()).render();
+    settle().await;
+    let button: HtmlElement = root
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into();
+    let window = web_sys::window().unwrap();
+
+    button.click();
+    settle().await;
+    for event in ["touchstart", "touchend"] {
+        window.dispatch_event(&Event::new(event).unwrap()).unwrap();
+    }
+    button.click();
+    settle().await;
+
+    assert_eq!(BEGINS.with(Cell::get), 1);
+    assert_eq!(CANCELS.with(Cell::get), 1);
+    app.destroy();
+    settle().await;
+}